@@ -0,0 +1,53 @@
+//! Compares `to_ascii` against formatting with `Display`.
+//!
+//! Run with `cargo bench --features types`.
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::Instant;
+
+use bounded_integer::{BoundedI64, BoundedU16, BoundedU8};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn bench(name: &str, mut f: impl FnMut(u32) -> usize) {
+    let start = Instant::now();
+    let mut total = 0;
+    for i in 0..ITERATIONS {
+        total += f(black_box(i));
+    }
+    let elapsed = start.elapsed();
+    black_box(total);
+    println!(
+        "{name:<24} {:>8.2} ns/iter",
+        elapsed.as_nanos() as f64 / f64::from(ITERATIONS),
+    );
+}
+
+macro_rules! compare {
+    ($name:literal, $ty:ty, $value:expr) => {{
+        let value = |i: u32| <$ty>::new_saturating($value(i));
+
+        let mut buf = [0; <$ty>::MAX_LEN];
+        bench(concat!($name, " to_ascii"), |i| {
+            value(i).to_ascii(&mut buf).len()
+        });
+
+        let mut string = String::with_capacity(<$ty>::MAX_LEN);
+        bench(concat!($name, " Display"), |i| {
+            string.clear();
+            write!(string, "{}", value(i)).unwrap();
+            string.len()
+        });
+    }};
+}
+
+fn main() {
+    compare!("minutes", BoundedU8<0, 59>, |i| (i % 60) as u8);
+    compare!("port", BoundedU16<1, 65535>, |i| (i | 1) as u16);
+    compare!(
+        "offset",
+        BoundedI64<-1_000_000_000_000, 1_000_000_000_000>,
+        |i: u32| { i64::from(i) * 0x9E37 - 500_000_000_000 }
+    );
+}
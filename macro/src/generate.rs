@@ -1,4 +1,4 @@
-use proc_macro2::{Ident, Punct, Spacing, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Punct, Spacing, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::Token;
 
@@ -19,24 +19,51 @@ pub(crate) fn generate(item: &BoundedInteger, tokens: &mut TokenStream) {
     generate_from_str(item, tokens);
     generate_fmt_traits(item, tokens);
     generate_to_primitive_traits(item, tokens);
-    if item.repr.is_usize() {
+    generate_key(item, tokens);
+    generate_bits(item, tokens);
+    if *item.range.start() >= BigInt::from(0) {
         generate_index_traits(item, tokens);
 
-        if item.std {
-            generate_index_traits_alloc(item, tokens, &Ident::new("std", Span::call_site()));
-        } else if item.alloc {
-            generate_index_traits_alloc(item, tokens, &Ident::new("alloc", Span::call_site()));
+        if item.alloc || item.std {
+            generate_index_traits_alloc(item, tokens);
         }
     }
     if item.arbitrary1 {
         generate_arbitrary1(item, tokens);
     }
+    if item.borsh1 {
+        generate_borsh1(item, tokens);
+    }
     if item.bytemuck1 {
         generate_bytemuck1(item, tokens);
     }
+    if item.clap4 {
+        generate_clap4(item, tokens);
+    }
+    if item.defmt1 {
+        generate_defmt1(item, tokens);
+    }
+    if item.pyo3028 {
+        generate_pyo3028(item, tokens);
+    }
+    if item.rkyv08 {
+        generate_rkyv08(item, tokens);
+    }
+    if item.rusqlite040 {
+        generate_rusqlite040(item, tokens);
+    }
+    if item.schemars1 {
+        generate_schemars1(item, tokens);
+    }
     if item.serde1 {
         generate_serde1(item, tokens);
     }
+    if item.ufmt02 {
+        generate_ufmt02(item, tokens);
+    }
+    if item.utoipa5 {
+        generate_utoipa5(item, tokens);
+    }
 
     if cfg!(feature = "generate_tests") {
         generate_tests(item, tokens);
@@ -47,6 +74,12 @@ fn generate_item(item: &BoundedInteger, tokens: &mut TokenStream) {
     let repr = &item.repr;
     let crate_path = &item.crate_path;
 
+    // Zerocopy 0.8 can only be told where it is with a string, which can't name `$crate`. This must
+    // precede all attributes.
+    if item.zerocopy08 {
+        tokens.extend(quote!(use #crate_path::__private::zerocopy08 as zerocopy08;));
+    }
+
     if item.zerocopy06 {
         let zerocopy = quote!(#crate_path::__private::zerocopy06);
 
@@ -62,6 +95,23 @@ fn generate_item(item: &BoundedInteger, tokens: &mut TokenStream) {
         }
     }
 
+    if item.zerocopy08 {
+        let zerocopy = quote!(#crate_path::__private::zerocopy08);
+        let mut derives =
+            quote!(#zerocopy::IntoBytes, #zerocopy::KnownLayout, #zerocopy::Immutable);
+        // Only the discriminants of enums restrict which values they can hold.
+        if let Kind::Enum(_) = item.kind {
+            derives.extend(quote!(, #zerocopy::TryFromBytes));
+        }
+        if let ReprSize::Fixed(ReprSizeFixed::Fixed8) = item.repr.size {
+            derives.extend(quote!(, #zerocopy::Unaligned));
+        }
+        tokens.extend(quote! {
+            #[derive(#derives)]
+            #[zerocopy(crate = "self::zerocopy08")]
+        });
+    }
+
     tokens.extend(quote! {
         #[derive(
             ::core::fmt::Debug,
@@ -124,7 +174,10 @@ fn generate_impl(item: &BoundedInteger, tokens: &mut TokenStream) {
     generate_min_max(item, &mut content);
     generate_unchecked_constructors(item, &mut content);
     generate_checked_constructors(item, &mut content);
+    generate_slice_constructors(item, &mut content);
     generate_from_str_radix(item, &mut content);
+    generate_byte_conversions(item, &mut content);
+    generate_format_methods(item, &mut content);
     generate_getters(item, &mut content);
     generate_inherent_operators(item, &mut content);
     generate_checked_operators(item, &mut content);
@@ -340,6 +393,135 @@ fn generate_checked_constructors(item: &BoundedInteger, tokens: &mut TokenStream
     });
 }
 
+fn generate_slice_constructors(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+
+    let safety_doc = "
+# Safety
+
+No value may be outside the valid range of values; they must not be less than
+[`MIN_VALUE`](Self::MIN_VALUE) or greater than [`MAX_VALUE`](Self::MAX_VALUE).\
+    ";
+
+    tokens.extend(quote! {
+        /// Creates a slice of bounded integers from a slice of primitives if all the values are
+        /// within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of the
+        /// first value that isn't.
+        #[inline]
+        #vis fn new_slice(
+            n: &[::core::primitive::#repr],
+        ) -> ::core::result::Result<&[Self], ::core::primitive::usize> {
+            match ::core::iter::Iterator::position(&mut n.iter(), |&n| !Self::in_range(n)) {
+                ::core::option::Option::Some(index) => ::core::result::Result::Err(index),
+                // SAFETY: We just checked that all the values are in range.
+                ::core::option::Option::None => {
+                    ::core::result::Result::Ok(unsafe { Self::new_slice_unchecked(n) })
+                }
+            }
+        }
+
+        /// Creates a mutable slice of bounded integers from a mutable slice of primitives if all
+        /// the values are within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns
+        /// the index of the first value that isn't.
+        #[inline]
+        #vis fn new_slice_mut(
+            n: &mut [::core::primitive::#repr],
+        ) -> ::core::result::Result<&mut [Self], ::core::primitive::usize> {
+            match ::core::iter::Iterator::position(&mut n.iter(), |&n| !Self::in_range(n)) {
+                ::core::option::Option::Some(index) => ::core::result::Result::Err(index),
+                // SAFETY: We just checked that all the values are in range.
+                ::core::option::Option::None => {
+                    ::core::result::Result::Ok(unsafe { Self::new_slice_mut_unchecked(n) })
+                }
+            }
+        }
+
+        /// Creates a slice of bounded integers from a slice of primitives without checking the
+        /// values.
+        #[doc = #safety_doc]
+        #[must_use]
+        #vis unsafe fn new_slice_unchecked(n: &[::core::primitive::#repr]) -> &[Self] {
+            ::core::debug_assert!(::core::iter::Iterator::all(
+                &mut n.iter(),
+                |&n| Self::in_range(n),
+            ));
+            &*(n as *const [::core::primitive::#repr] as *const [Self])
+        }
+
+        /// Creates a mutable slice of bounded integers from a mutable slice of primitives without
+        /// checking the values.
+        #[doc = #safety_doc]
+        #[must_use]
+        #vis unsafe fn new_slice_mut_unchecked(
+            n: &mut [::core::primitive::#repr],
+        ) -> &mut [Self] {
+            ::core::debug_assert!(::core::iter::Iterator::all(
+                &mut n.iter(),
+                |&n| Self::in_range(n),
+            ));
+            &mut *(n as *mut [::core::primitive::#repr] as *mut [Self])
+        }
+
+        /// Creates an array of bounded integers from an array of primitives if all the values are
+        /// within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of the
+        /// first value that isn't.
+        #[inline]
+        #vis fn new_array<const N: ::core::primitive::usize>(
+            n: [::core::primitive::#repr; N],
+        ) -> ::core::result::Result<[Self; N], ::core::primitive::usize> {
+            Self::new_array_ref(&n)?;
+            // SAFETY: We just checked that all the values are in range.
+            ::core::result::Result::Ok(n.map(|n| unsafe { Self::new_unchecked(n) }))
+        }
+
+        /// Creates a reference to an array of bounded integers from a reference to an array of
+        /// primitives if all the values are within the range
+        /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of the first value that
+        /// isn't.
+        #[inline]
+        #vis fn new_array_ref<const N: ::core::primitive::usize>(
+            n: &[::core::primitive::#repr; N],
+        ) -> ::core::result::Result<&[Self; N], ::core::primitive::usize> {
+            let slice = Self::new_slice(n)?;
+            // SAFETY: The slice has the same length as the array.
+            ::core::result::Result::Ok(unsafe { &*slice.as_ptr().cast::<[Self; N]>() })
+        }
+
+        /// Creates a mutable reference to an array of bounded integers from a mutable reference
+        /// to an array of primitives if all the values are within the range
+        /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of the first value that
+        /// isn't.
+        #[inline]
+        #vis fn new_array_mut<const N: ::core::primitive::usize>(
+            n: &mut [::core::primitive::#repr; N],
+        ) -> ::core::result::Result<&mut [Self; N], ::core::primitive::usize> {
+            let slice = Self::new_slice_mut(n)?;
+            // SAFETY: The slice has the same length as the array.
+            ::core::result::Result::Ok(unsafe { &mut *slice.as_mut_ptr().cast::<[Self; N]>() })
+        }
+
+        /// Converts a slice of bounded integers to a slice of primitives.
+        #[must_use]
+        #[inline]
+        #vis const fn as_inner_slice(slice: &[Self]) -> &[::core::primitive::#repr] {
+            // SAFETY: The bounded integer has the same layout as the primitive.
+            unsafe { &*(slice as *const [Self] as *const [::core::primitive::#repr]) }
+        }
+
+        /// Converts a reference to an array of bounded integers to a reference to an array of
+        /// primitives.
+        #[must_use]
+        #[inline]
+        #vis const fn as_inner_array<const N: ::core::primitive::usize>(
+            array: &[Self; N],
+        ) -> &[::core::primitive::#repr; N] {
+            // SAFETY: The bounded integer has the same layout as the primitive.
+            unsafe { &*(array as *const [Self; N] as *const [::core::primitive::#repr; N]) }
+        }
+    });
+}
+
 fn generate_from_str_radix(item: &BoundedInteger, tokens: &mut TokenStream) {
     let repr = &item.repr;
     let vis = &item.vis;
@@ -370,6 +552,173 @@ fn generate_from_str_radix(item: &BoundedInteger, tokens: &mut TokenStream) {
     });
 }
 
+fn generate_byte_conversions(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let repr = &item.repr;
+    let vis = &item.vis;
+    let crate_path = &item.crate_path;
+
+    let bytes = quote!([::core::primitive::u8; ::core::mem::size_of::<::core::primitive::#repr>()]);
+
+    tokens.extend(quote! {
+        /// Creates a bounded integer, or returns an error of kind `BelowMin` or `AboveMax` if the
+        /// value is out of range.
+        const fn new_or_error(
+            n: ::core::primitive::#repr,
+        ) -> ::core::result::Result<Self, #crate_path::ParseError> {
+            match Self::new(n) {
+                ::core::option::Option::Some(bounded) => ::core::result::Result::Ok(bounded),
+                ::core::option::Option::None if n < Self::MIN_VALUE => {
+                    ::core::result::Result::Err(#crate_path::__private::error_below_min())
+                }
+                ::core::option::Option::None => {
+                    ::core::result::Result::Err(#crate_path::__private::error_above_max())
+                }
+            }
+        }
+    });
+
+    for (order, desc) in [
+        ("le", "little-endian"),
+        ("be", "big-endian"),
+        ("ne", "native"),
+    ] {
+        let to_bytes = Ident::new(&format!("to_{order}_bytes"), Span::call_site());
+        let try_from_bytes = Ident::new(&format!("try_from_{order}_bytes"), Span::call_site());
+        let from_bytes = Ident::new(&format!("from_{order}_bytes"), Span::call_site());
+
+        let to_doc = format!(
+            "Returns the memory representation of the bounded integer as a byte array in {desc} \
+            byte order."
+        );
+        let from_doc = format!(
+            "Creates a bounded integer from its representation as a byte array in {desc} byte \
+            order."
+        );
+
+        tokens.extend(quote! {
+            #[doc = #to_doc]
+            #[must_use]
+            #[inline]
+            #vis const fn #to_bytes(self) -> #bytes {
+                self.get().#to_bytes()
+            }
+
+            #[doc = #from_doc]
+            ///
+            /// # Errors
+            ///
+            /// Returns an error of kind `BelowMin` or `AboveMax` if the value is out of range.
+            #[inline]
+            #vis const fn #try_from_bytes(
+                bytes: #bytes,
+            ) -> ::core::result::Result<Self, #crate_path::ParseError> {
+                Self::new_or_error(<::core::primitive::#repr>::#from_bytes(bytes))
+            }
+        });
+    }
+}
+
+fn generate_format_methods(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let vis = &item.vis;
+    let crate_path = &item.crate_path;
+
+    let magnitude = |n: &BigInt| {
+        Literal::u128_suffixed(
+            n.magnitude()
+                .try_into()
+                .expect("magnitude of a primitive should fit in a u128"),
+        )
+    };
+    let min_magnitude = magnitude(item.range.start());
+    let max_magnitude = magnitude(item.range.end());
+
+    let max_len = item
+        .range
+        .start()
+        .to_string()
+        .len()
+        .max(item.range.end().to_string().len());
+
+    let sign_magnitude = if *item.range.start() >= BigInt::from(0) {
+        quote!((false, self.get() as ::core::primitive::u128))
+    } else {
+        quote! {{
+            let n = self.get();
+            (n < 0, (n as ::core::primitive::i128).unsigned_abs())
+        }}
+    };
+
+    tokens.extend(quote! {
+        /// The number of decimal digits of the longest value of the bounded integer, not including
+        /// the sign.
+        #vis const MAX_DIGITS: ::core::primitive::usize = Self::max_digits(10);
+        /// The length of the longest decimal representation of a value of the bounded integer,
+        /// including the sign.
+        #vis const MAX_LEN: ::core::primitive::usize = #max_len;
+
+        /// Returns the number of digits of the longest value of the bounded integer in a given
+        /// base, not including the sign.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `radix` is below 2 or above 36.
+        #[must_use]
+        #vis const fn max_digits(radix: ::core::primitive::u32) -> ::core::primitive::usize {
+            #crate_path::__private::max_digits(#min_magnitude, #max_magnitude, radix)
+        }
+
+        /// Returns an object that displays the bounded integer in decimal, padded with leading
+        /// zeros to [`MAX_DIGITS`](Self::MAX_DIGITS) digits.
+        ///
+        /// The sign, if any, is written before the zeros and does not count towards the digits.
+        #[must_use]
+        #vis fn display_padded(self) -> impl ::core::fmt::Display {
+            let (negative, magnitude) = #sign_magnitude;
+            #crate_path::__private::Formatted::new(negative, magnitude, 10, Self::MAX_DIGITS)
+        }
+
+        /// Returns an object that displays the bounded integer in a given base, using lowercase
+        /// letters for digits above 9.
+        ///
+        /// This is the inverse of [`from_str_radix`](Self::from_str_radix).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `radix` is below 2 or above 36.
+        #[must_use]
+        #vis fn format_radix(self, radix: ::core::primitive::u32) -> impl ::core::fmt::Display {
+            let (negative, magnitude) = #sign_magnitude;
+            #crate_path::__private::Formatted::new(negative, magnitude, radix, 0)
+        }
+
+        /// Writes the bounded integer in decimal to the end of a buffer, returning the written part
+        /// as a string.
+        ///
+        /// This is much faster than formatting with [`Display`](::core::fmt::Display) and does
+        /// not require a [`Write`](::core::fmt::Write). The buffer must be at least
+        /// [`MAX_LEN`](Self::MAX_LEN) bytes long, which is checked at compile time.
+        #[must_use]
+        #[inline]
+        #vis fn to_ascii<const N: ::core::primitive::usize>(
+            self,
+            buf: &mut [::core::primitive::u8; N],
+        ) -> &::core::primitive::str {
+            struct AsciiBuffer<const N: ::core::primitive::usize>;
+            impl<const N: ::core::primitive::usize> AsciiBuffer<N> {
+                const OK: () = ::core::assert!(
+                    N >= #ident::MAX_LEN,
+                    "buffer must be at least `MAX_LEN` bytes long",
+                );
+            }
+            let () = AsciiBuffer::<N>::OK;
+
+            let (negative, magnitude) = #sign_magnitude;
+            #crate_path::__private::to_ascii(negative, magnitude, buf)
+        }
+    });
+}
+
 fn generate_getters(item: &BoundedInteger, tokens: &mut TokenStream) {
     let repr = &item.repr;
     let vis = &item.vis;
@@ -976,71 +1325,172 @@ fn generate_to_primitive_traits(item: &BoundedInteger, tokens: &mut TokenStream)
     }
 }
 
+fn generate_key(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+
+    let difference = Literal::u128_suffixed(
+        (item.range.end() - item.range.start())
+            .try_into()
+            .expect("range of a primitive should fit in a u128"),
+    );
+
+    tokens.extend(quote! {
+        unsafe impl #crate_path::BoundedKey for #ident {
+            const COUNT: ::core::primitive::usize = #crate_path::__private::key_count(
+                ::core::option::Option::Some(#difference),
+            );
+
+            #[inline]
+            fn to_index(self) -> ::core::primitive::usize {
+                (self.get() as ::core::primitive::i128)
+                    .wrapping_sub(Self::MIN_VALUE as ::core::primitive::i128)
+                    as ::core::primitive::usize
+            }
+
+            #[inline]
+            unsafe fn from_index_unchecked(index: ::core::primitive::usize) -> Self {
+                Self::new_unchecked(
+                    (Self::MIN_VALUE as ::core::primitive::i128)
+                        .wrapping_add(index as ::core::primitive::i128)
+                        as ::core::primitive::#repr
+                )
+            }
+        }
+    });
+}
+
+fn generate_bits(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+
+    let difference = Literal::u128_suffixed(
+        (item.range.end() - item.range.start())
+            .try_into()
+            .expect("range of a primitive should fit in a u128"),
+    );
+
+    tokens.extend(quote! {
+        impl #crate_path::BoundedBits for #ident {
+            const MAX_OFFSET: ::core::primitive::u128 = #difference;
+
+            #[inline]
+            fn to_offset_bits(self) -> ::core::primitive::u128 {
+                (self.get() as ::core::primitive::i128)
+                    .wrapping_sub(Self::MIN_VALUE as ::core::primitive::i128)
+                    as ::core::primitive::u128
+            }
+
+            #[inline]
+            fn from_offset_bits(
+                bits: ::core::primitive::u128,
+            ) -> ::core::option::Option<Self> {
+                if bits > Self::MAX_OFFSET {
+                    return ::core::option::Option::None;
+                }
+                // SAFETY: We just checked that the offset is in range.
+                ::core::option::Option::Some(unsafe {
+                    Self::new_unchecked(
+                        (Self::MIN_VALUE as ::core::primitive::i128)
+                            .wrapping_add(bits as ::core::primitive::i128)
+                            as ::core::primitive::#repr
+                    )
+                })
+            }
+        }
+    });
+}
+
 fn generate_index_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
 
     tokens.extend(quote! {
+        /// Converts the index to a `usize`, telling the optimizer that it is in range so that
+        /// bounds checks against a long enough collection can be elided.
+        #[inline(always)]
+        fn index_to_usize(index: #ident) -> ::core::primitive::usize {
+            let index = index.get();
+            if !#ident::in_range(index) {
+                // SAFETY: Bounded integers are never outside their range.
+                unsafe { ::core::hint::unreachable_unchecked() }
+            }
+            // An index that does not fit in a `usize` is out of bounds of any collection, as is
+            // `usize::MAX`.
+            ::core::result::Result::unwrap_or(
+                <::core::primitive::usize as ::core::convert::TryFrom<::core::primitive::#repr>>::try_from(index),
+                ::core::primitive::usize::MAX,
+            )
+        }
+
         impl<T> ::core::ops::Index<#ident> for [T] {
             type Output = T;
 
             #[inline]
             fn index(&self, index: #ident) -> &Self::Output {
-                &self[index.get()]
+                &self[index_to_usize(index)]
             }
         }
 
-        impl<T ::core::ops::IndexMut<#ident> for [T] {
+        impl<T> ::core::ops::IndexMut<#ident> for [T] {
             #[inline]
             fn index_mut(&mut self, index: #ident) -> &mut Self::Output {
-                &mut self[index.get()]
+                &mut self[index_to_usize(index)]
+            }
+        }
+
+        impl #crate_path::__private::SliceIndex for #ident {
+            #[inline]
+            fn to_slice_index(self) -> ::core::primitive::usize {
+                index_to_usize(self)
             }
         }
     });
 }
 
-fn generate_index_traits_alloc(
-    item: &BoundedInteger,
-    tokens: &mut TokenStream,
-    alloc_or_std: &Ident,
-) {
+fn generate_index_traits_alloc(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
+    let crate_path = &item.crate_path;
 
     tokens.extend(quote! {
-        impl<T ::core::ops::Index<#ident>
-            for ::#alloc_or_std::vec::Vec<T>
+        impl<T> ::core::ops::Index<#ident>
+            for #crate_path::__private::Vec<T>
         {
             type Output = T;
 
             #[inline]
             fn index(&self, index: #ident) -> &Self::Output {
-                &self[index.get()]
+                &self[index_to_usize(index)]
             }
         }
-        impl<T ::core::ops::Index<#ident>
-            for ::#alloc_or_std::collections::VecDeque<T>
+        impl<T> ::core::ops::Index<#ident>
+            for #crate_path::__private::VecDeque<T>
         {
             type Output = T;
 
             #[inline]
             fn index(&self, index: #ident) -> &Self::Output {
-                &self[index.get()]
+                &self[index_to_usize(index)]
             }
         }
 
-        impl<T ::core::ops::IndexMut<#ident>
-            for ::#alloc_or_std::vec::Vec<T>
+        impl<T> ::core::ops::IndexMut<#ident>
+            for #crate_path::__private::Vec<T>
         {
             #[inline]
             fn index_mut(&mut self, index: #ident) -> &mut Self::Output {
-                &mut self[index.get()]
+                &mut self[index_to_usize(index)]
             }
         }
-        impl<T ::core::ops::IndexMut<#ident>
-            for ::#alloc_or_std::collections::VecDeque<T>
+        impl<T> ::core::ops::IndexMut<#ident>
+            for #crate_path::__private::VecDeque<T>
         {
             #[inline]
             fn index_mut(&mut self, index: #ident) -> &mut Self::Output {
-                &mut self[index.get()]
+                &mut self[index_to_usize(index)]
             }
         }
     });
@@ -1068,6 +1518,47 @@ fn generate_arbitrary1(item: &BoundedInteger, tokens: &mut TokenStream) {
     });
 }
 
+fn generate_borsh1(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let borsh = quote!(#crate_path::__private::borsh1);
+    let name = ident.to_string();
+
+    tokens.extend(quote! {
+        impl #borsh::BorshSerialize for #ident {
+            fn serialize<W: #borsh::io::Write>(&self, writer: &mut W) -> #borsh::io::Result<()> {
+                <::core::primitive::#repr as #borsh::BorshSerialize>::serialize(&self.get(), writer)
+            }
+        }
+
+        impl #borsh::BorshDeserialize for #ident {
+            fn deserialize_reader<R: #borsh::io::Read>(reader: &mut R) -> #borsh::io::Result<Self> {
+                let value = <::core::primitive::#repr as #borsh::BorshDeserialize>::deserialize_reader(reader)?;
+                Self::new(value).ok_or_else(#crate_path::__private::borsh_out_of_range)
+            }
+        }
+
+        impl #borsh::BorshSchema for #ident {
+            fn add_definitions_recursively(
+                definitions: &mut #crate_path::__private::BTreeMap<
+                    #borsh::schema::Declaration,
+                    #borsh::schema::Definition,
+                >,
+            ) {
+                #crate_path::__private::borsh_add_definitions::<::core::primitive::#repr>(
+                    <Self as #borsh::BorshSchema>::declaration(),
+                    definitions,
+                );
+            }
+
+            fn declaration() -> #borsh::schema::Declaration {
+                #crate_path::__private::borsh_declaration(#name, Self::MIN_VALUE, Self::MAX_VALUE)
+            }
+        }
+    });
+}
+
 fn generate_bytemuck1(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
     let repr = &item.repr;
@@ -1080,6 +1571,17 @@ fn generate_bytemuck1(item: &BoundedInteger, tokens: &mut TokenStream) {
             const MAX_VALUE: ::core::primitive::#repr = Self::MAX_VALUE;
             const MIN_VALUE: ::core::primitive::#repr = Self::MIN_VALUE;
         }
+
+        unsafe impl #bytemuck::NoUninit for #ident {}
+
+        unsafe impl #bytemuck::CheckedBitPattern for #ident {
+            type Bits = ::core::primitive::#repr;
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &::core::primitive::#repr) -> ::core::primitive::bool {
+                Self::in_range(*bits)
+            }
+        }
     });
 
     if item.range.contains(&BigInt::from(0)) {
@@ -1089,6 +1591,369 @@ fn generate_bytemuck1(item: &BoundedInteger, tokens: &mut TokenStream) {
     }
 }
 
+fn generate_clap4(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let clap = quote!(#crate_path::__private::clap4);
+
+    // Only list the values of enums small enough for them to fit in the help text.
+    let possible_values = match &item.kind {
+        Kind::Enum(_) if item.range.end() - item.range.start() < BigInt::from(16) => {
+            let mut values = TokenStream::new();
+            let mut value = item.range.start().clone();
+            while value <= *item.range.end() {
+                let value_str = value.to_string();
+                values.extend(quote!(#value_str,));
+                value += 1;
+            }
+            quote! {
+                const POSSIBLE_VALUES: &'static [&'static ::core::primitive::str] = &[#values];
+            }
+        }
+        _ => TokenStream::new(),
+    };
+
+    tokens.extend(quote! {
+        impl #clap::builder::ValueParserFactory for #ident {
+            type Parser = #crate_path::BoundedValueParser<Self>;
+
+            fn value_parser() -> Self::Parser {
+                #crate_path::BoundedValueParser::new()
+            }
+        }
+
+        impl #crate_path::__private::ClapParse for #ident {
+            type Inner = ::core::primitive::#repr;
+            const MIN: ::core::primitive::#repr = Self::MIN_VALUE;
+            const MAX: ::core::primitive::#repr = Self::MAX_VALUE;
+            #possible_values
+        }
+    });
+}
+
+fn generate_defmt1(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let defmt = quote!(#crate_path::__private::defmt1);
+
+    tokens.extend(quote! {
+        impl #defmt::Format for #ident {
+            fn format(&self, fmt: #defmt::Formatter<'_>) {
+                <::core::primitive::#repr as #defmt::Format>::format(&self.get(), fmt);
+            }
+        }
+    });
+}
+
+fn archived_ident(item: &BoundedInteger) -> Ident {
+    let ident = &item.ident;
+    item.archived
+        .clone()
+        .unwrap_or_else(|| Ident::new(&format!("Archived{ident}"), ident.span()))
+}
+
+fn generate_pyo3028(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let crate_path = &item.crate_path;
+    let pyo3 = quote!(#crate_path::__private::pyo3028);
+
+    let into_pyobject = quote! {
+        type Target = #pyo3::types::PyInt;
+        type Output = #pyo3::Bound<'py, #pyo3::types::PyInt>;
+        type Error = ::core::convert::Infallible;
+
+        fn into_pyobject(
+            self,
+            py: #pyo3::Python<'py>,
+        ) -> ::core::result::Result<Self::Output, Self::Error> {
+            #pyo3::IntoPyObject::into_pyobject(self.get(), py)
+        }
+    };
+
+    tokens.extend(quote! {
+        impl<'py> #pyo3::FromPyObject<'_, 'py> for #ident {
+            type Error = #pyo3::PyErr;
+
+            fn extract(obj: #pyo3::Borrowed<'_, 'py, #pyo3::PyAny>) -> #pyo3::PyResult<Self> {
+                #crate_path::__private::pyo3_extract(
+                    obj,
+                    Self::new,
+                    Self::MIN_VALUE,
+                    Self::MAX_VALUE,
+                )
+            }
+        }
+
+        impl<'py> #pyo3::IntoPyObject<'py> for #ident {
+            #into_pyobject
+        }
+
+        impl<'py> #pyo3::IntoPyObject<'py> for &#ident {
+            #into_pyobject
+        }
+    });
+}
+
+fn generate_rkyv08(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let archived_ident = archived_ident(item);
+    let vis = &item.vis;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let rkyv = quote!(#crate_path::__private::rkyv08);
+    let error = quote!(#crate_path::__private::RkyvArchiveError);
+    let fixed = match (repr.sign, repr.size) {
+        (Unsigned, ReprSize::Pointer) => quote!(#rkyv::primitive::FixedUsize),
+        (Signed, ReprSize::Pointer) => quote!(#rkyv::primitive::FixedIsize),
+        (_, ReprSize::Fixed(_)) => quote!(::core::primitive::#repr),
+    };
+    let doc = format!("An archived [`{ident}`].");
+
+    tokens.extend(quote! {
+        #[doc = #doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy)]
+        #[repr(transparent)]
+        #vis struct #archived_ident(#rkyv::Archived<#fixed>);
+
+        // SAFETY: `Archived<Fixed>` is portable and this type is a transparent wrapper of it.
+        unsafe impl #rkyv::Portable for #archived_ident {}
+
+        // SAFETY: `Archived<Fixed>` has no undefined bytes and this type is a transparent wrapper
+        // of it.
+        unsafe impl #rkyv::traits::NoUndef for #archived_ident {}
+
+        impl #archived_ident {
+            /// Gets the value of the archived integer.
+            #[must_use]
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            #vis fn get(self) -> ::core::primitive::#repr {
+                <#fixed as ::core::convert::From<_>>::from(self.0) as ::core::primitive::#repr
+            }
+
+            /// Gets the value of the archived integer as a bounded integer.
+            #[must_use]
+            #[inline]
+            #vis fn get_bounded(self) -> #ident {
+                // SAFETY: Archived bounded integers are always in range, since they are either
+                // written from a bounded integer or validated.
+                unsafe { #ident::new_unchecked(self.get()) }
+            }
+        }
+
+        impl #rkyv::Archive for #ident {
+            type Archived = #archived_ident;
+            type Resolver = ();
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            fn resolve(&self, (): Self::Resolver, out: #rkyv::Place<Self::Archived>) {
+                let archived = <#rkyv::Archived<#fixed> as ::core::convert::From<#fixed>>::from(
+                    self.get() as #fixed,
+                );
+                out.write(#archived_ident(archived));
+            }
+        }
+
+        impl<__S> #rkyv::Serialize<__S> for #ident
+        where
+            __S: #rkyv::rancor::Fallible + ?::core::marker::Sized,
+            __S::Error: #rkyv::rancor::Source,
+        {
+            #[allow(clippy::useless_conversion)]
+            fn serialize(&self, _: &mut __S) -> ::core::result::Result<(), __S::Error> {
+                match <#fixed as ::core::convert::TryFrom<_>>::try_from(self.get()) {
+                    ::core::result::Result::Ok(_) => ::core::result::Result::Ok(()),
+                    ::core::result::Result::Err(_) => ::core::result::Result::Err(
+                        <__S::Error as #rkyv::rancor::Source>::new(#error::TooLarge),
+                    ),
+                }
+            }
+        }
+
+        impl<__D> #rkyv::Deserialize<#ident, __D> for #archived_ident
+        where
+            __D: #rkyv::rancor::Fallible + ?::core::marker::Sized,
+        {
+            #[inline]
+            fn deserialize(&self, _: &mut __D) -> ::core::result::Result<#ident, __D::Error> {
+                ::core::result::Result::Ok(self.get_bounded())
+            }
+        }
+
+        // SAFETY: `check_bytes` only returns `Ok` if the inner value is valid and in range.
+        unsafe impl<__C> #rkyv::bytecheck::CheckBytes<__C> for #archived_ident
+        where
+            __C: #rkyv::rancor::Fallible + ?::core::marker::Sized,
+            __C::Error: #rkyv::rancor::Source,
+            #rkyv::Archived<#fixed>: #rkyv::bytecheck::CheckBytes<__C>,
+        {
+            #[allow(clippy::useless_conversion)]
+            unsafe fn check_bytes(
+                value: *const Self,
+                context: &mut __C,
+            ) -> ::core::result::Result<(), __C::Error> {
+                let inner = value.cast::<#rkyv::Archived<#fixed>>();
+                // SAFETY: The caller guarantees that `value` is aligned and points to enough bytes
+                // for `Self`, which is a transparent wrapper of `Archived<Fixed>`.
+                unsafe {
+                    <#rkyv::Archived<#fixed> as #rkyv::bytecheck::CheckBytes<__C>>::check_bytes(
+                        inner, context,
+                    )?;
+                }
+                // SAFETY: We just checked that the inner value is valid.
+                let n = <#fixed as ::core::convert::From<_>>::from(unsafe { *inner });
+                match <::core::primitive::#repr as ::core::convert::TryFrom<_>>::try_from(n) {
+                    ::core::result::Result::Ok(n) if #ident::in_range(n) => {
+                        ::core::result::Result::Ok(())
+                    }
+                    _ => ::core::result::Result::Err(
+                        <__C::Error as #rkyv::rancor::Source>::new(#error::OutOfRange),
+                    ),
+                }
+            }
+        }
+    });
+
+    generate_rkyv08_archived_traits(item, tokens);
+}
+
+fn generate_rkyv08_archived_traits(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let archived_ident = archived_ident(item);
+    let repr = &item.repr;
+
+    tokens.extend(quote! {
+        impl ::core::convert::From<#archived_ident> for #ident {
+            fn from(archived: #archived_ident) -> Self {
+                archived.get_bounded()
+            }
+        }
+
+        impl ::core::convert::From<#archived_ident> for ::core::primitive::#repr {
+            fn from(archived: #archived_ident) -> Self {
+                archived.get()
+            }
+        }
+
+        impl ::core::cmp::PartialEq for #archived_ident {
+            fn eq(&self, other: &Self) -> ::core::primitive::bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl ::core::cmp::Eq for #archived_ident {}
+
+        impl ::core::cmp::PartialEq<::core::primitive::#repr> for #archived_ident {
+            fn eq(&self, other: &::core::primitive::#repr) -> ::core::primitive::bool {
+                self.get() == *other
+            }
+        }
+
+        impl ::core::cmp::PartialEq<#ident> for #archived_ident {
+            fn eq(&self, other: &#ident) -> ::core::primitive::bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl ::core::cmp::PartialOrd for #archived_ident {
+            fn partial_cmp(
+                &self,
+                other: &Self,
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for #archived_ident {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.get(), &other.get())
+            }
+        }
+
+        impl ::core::hash::Hash for #archived_ident {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.get(), state);
+            }
+        }
+
+        impl ::core::fmt::Debug for #archived_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl ::core::fmt::Display for #archived_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.get(), f)
+            }
+        }
+    });
+}
+
+fn generate_rusqlite040(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let crate_path = &item.crate_path;
+    let rusqlite = quote!(#crate_path::__private::rusqlite040);
+
+    tokens.extend(quote! {
+        impl #rusqlite::ToSql for #ident {
+            fn to_sql(&self) -> #rusqlite::Result<#rusqlite::types::ToSqlOutput<'_>> {
+                #crate_path::__private::rusqlite_to_sql(self.get())
+            }
+        }
+
+        impl #rusqlite::types::FromSql for #ident {
+            fn column_result(
+                value: #rusqlite::types::ValueRef<'_>,
+            ) -> #rusqlite::types::FromSqlResult<Self> {
+                #crate_path::__private::rusqlite_from_sql(value, Self::new)
+            }
+        }
+    });
+}
+
+fn generate_schemars1(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let schemars = quote!(#crate_path::__private::schemars1);
+    let name = ident.to_string();
+    let list_values = enum_values(item).map(|values| {
+        quote!(#crate_path::__private::schemars_list_values::<::core::primitive::#repr>(
+            &mut schema,
+            &[#values],
+        );)
+    });
+
+    tokens.extend(quote! {
+        impl #schemars::JsonSchema for #ident {
+            fn schema_name() -> #crate_path::__private::Cow<'static, ::core::primitive::str> {
+                #crate_path::__private::Cow::Borrowed(#name)
+            }
+
+            fn schema_id() -> #crate_path::__private::Cow<'static, ::core::primitive::str> {
+                #crate_path::__private::Cow::Borrowed(
+                    ::core::concat!(::core::module_path!(), "::", #name),
+                )
+            }
+
+            fn json_schema(generator: &mut #schemars::SchemaGenerator) -> #schemars::Schema {
+                #[allow(unused_mut)]
+                let mut schema = #crate_path::__private::schemars_schema(
+                    generator,
+                    Self::MIN_VALUE,
+                    Self::MAX_VALUE,
+                );
+                #list_values
+                schema
+            }
+        }
+    });
+}
+
 fn generate_serde1(item: &BoundedInteger, tokens: &mut TokenStream) {
     let ident = &item.ident;
     let repr = &item.repr;
@@ -1132,6 +1997,84 @@ fn generate_serde1(item: &BoundedInteger, tokens: &mut TokenStream) {
             }
         }
     });
+
+    tokens.extend(quote! {
+        impl #crate_path::__private::SerdeLenient for #ident {
+            const SIGNED: ::core::primitive::bool = <::core::primitive::#repr>::MIN != 0;
+            const INNER_BITS: ::core::primitive::u32 = <::core::primitive::#repr>::BITS;
+            const MIN_BITS: ::core::primitive::u128 = Self::MIN_VALUE as ::core::primitive::u128;
+        }
+    });
+}
+
+fn generate_ufmt02(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let ufmt = quote!(#crate_path::__private::ufmt02);
+
+    for fmt_trait in [quote!(uDisplay), quote!(uDebug)] {
+        tokens.extend(quote! {
+            impl #ufmt::#fmt_trait for #ident {
+                fn fmt<W>(
+                    &self,
+                    f: &mut #ufmt::Formatter<'_, W>,
+                ) -> ::core::result::Result<(), <W as #ufmt::uWrite>::Error>
+                where
+                    W: #ufmt::uWrite + ?::core::marker::Sized,
+                {
+                    <::core::primitive::#repr as #ufmt::#fmt_trait>::fmt(&self.get(), f)
+                }
+            }
+        });
+    }
+}
+
+fn generate_utoipa5(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let repr = &item.repr;
+    let crate_path = &item.crate_path;
+    let utoipa = quote!(#crate_path::__private::utoipa5);
+    let name = ident.to_string();
+    let list_values = enum_values(item).map(|values| {
+        quote!(#crate_path::__private::utoipa_list_values::<::core::primitive::#repr>(
+            &mut schema,
+            &[#values],
+        );)
+    });
+
+    tokens.extend(quote! {
+        impl #utoipa::PartialSchema for #ident {
+            fn schema() -> #utoipa::openapi::RefOr<#utoipa::openapi::schema::Schema> {
+                #[allow(unused_mut)]
+                let mut schema =
+                    #crate_path::__private::utoipa_schema(Self::MIN_VALUE, Self::MAX_VALUE);
+                #list_values
+                schema
+            }
+        }
+
+        impl #utoipa::ToSchema for #ident {
+            fn name() -> #crate_path::__private::Cow<'static, ::core::primitive::str> {
+                #crate_path::__private::Cow::Borrowed(#name)
+            }
+        }
+    });
+}
+
+/// The comma-separated values of a bounded enum, or `None` for a bounded struct.
+fn enum_values(item: &BoundedInteger) -> Option<TokenStream> {
+    let Kind::Enum(_) = item.kind else {
+        return None;
+    };
+    let mut values = TokenStream::new();
+    let mut value = item.range.start().clone();
+    while value <= *item.range.end() {
+        let literal = item.repr.number_literal(&value);
+        values.extend(quote!(#literal,));
+        value += 1;
+    }
+    Some(values)
 }
 
 fn generate_tests(item: &BoundedInteger, tokens: &mut TokenStream) {
@@ -1139,6 +2082,8 @@ fn generate_tests(item: &BoundedInteger, tokens: &mut TokenStream) {
 
     generate_test_range(item, &mut tests);
     generate_test_arithmetic(item, &mut tests);
+    generate_test_key(item, &mut tests);
+    generate_test_format(item, &mut tests);
 
     tokens.extend(quote! {
         mod tests {
@@ -1301,6 +2246,54 @@ fn generate_test_arithmetic(item: &BoundedInteger, tokens: &mut TokenStream) {
     });
 }
 
+fn generate_test_key(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+    let crate_path = &item.crate_path;
+
+    tokens.extend(quote! {
+        #[test]
+        fn key() {
+            use #crate_path::BoundedKey;
+
+            assert_eq!(#ident::MIN.to_index(), 0);
+            assert_eq!(#ident::MAX.to_index(), #ident::COUNT - 1);
+            assert_eq!(#ident::from_index(0), Some(#ident::MIN));
+            assert_eq!(#ident::from_index(#ident::COUNT - 1), Some(#ident::MAX));
+            assert_eq!(#ident::from_index(#ident::COUNT), None);
+        }
+    });
+}
+
+fn generate_test_format(item: &BoundedInteger, tokens: &mut TokenStream) {
+    let ident = &item.ident;
+
+    let digits = |radix: u32| {
+        item.range
+            .start()
+            .magnitude()
+            .max(item.range.end().magnitude())
+            .to_str_radix(radix)
+            .len()
+    };
+    let decimal_digits = digits(10);
+    let binary_digits = digits(2);
+    let min_len = item.range.start().to_string().len();
+    let max_len = item.range.end().to_string().len();
+
+    tokens.extend(quote! {
+        #[test]
+        fn format() {
+            assert_eq!(#ident::MAX_DIGITS, #decimal_digits);
+            assert_eq!(#ident::max_digits(2), #binary_digits);
+
+            assert_eq!(#ident::MAX_LEN, ::core::cmp::max(#min_len, #max_len));
+            let mut buf = [0; #ident::MAX_LEN];
+            assert_eq!(#ident::MIN.to_ascii(&mut buf).len(), #min_len);
+            assert_eq!(#ident::MAX.to_ascii(&mut buf).len(), #max_len);
+        }
+    });
+}
+
 fn enum_variant(i: &BigInt) -> Ident {
     Ident::new(
         &match i.sign() {
@@ -1323,7 +2316,7 @@ mod tests {
         input: TokenStream,
         expected: TokenStream,
     ) {
-        let input = quote!([::path] false false false false false false false #input);
+        let input = quote!([::path] false false false false false false false false false false false false false false false false false #input);
         let item = match parse2::<BoundedInteger>(input.clone()) {
             Ok(item) => item,
             Err(e) => panic!("Failed to parse '{input}': {e}"),
@@ -35,7 +35,10 @@ pub fn bounded_integer(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
     let ident = &item.ident;
     let original_visibility = item.vis;
 
-    let import = quote!(#original_visibility use #module_name::#ident);
+    let mut import = quote!(#original_visibility use #module_name::#ident;);
+    if let Some(archived) = item.archived.as_ref().filter(|_| item.rkyv08) {
+        import.extend(quote!(#original_visibility use #module_name::#archived;));
+    }
 
     item.vis = raise_one_level(original_visibility);
     let mut result = TokenStream::new();
@@ -46,7 +49,7 @@ pub fn bounded_integer(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
         mod #module_name {
             #result
         }
-        #import;
+        #import
     )
     .into()
 }
@@ -59,15 +62,26 @@ struct BoundedInteger {
     // Optional features
     alloc: bool,
     arbitrary1: bool,
+    borsh1: bool,
     bytemuck1: bool,
+    clap4: bool,
+    defmt1: bool,
+    pyo3028: bool,
+    rkyv08: bool,
+    rusqlite040: bool,
+    schemars1: bool,
     serde1: bool,
     std: bool,
+    ufmt02: bool,
+    utoipa5: bool,
     zerocopy06: bool,
+    zerocopy08: bool,
     step_trait: bool,
 
     // The item itself
     attrs: Vec<Attribute>,
     repr: Repr,
+    archived: Option<Ident>,
     vis: Visibility,
     kind: Kind,
     ident: Ident,
@@ -76,15 +90,26 @@ struct BoundedInteger {
 }
 
 impl Parse for BoundedInteger {
+    #[allow(clippy::too_many_lines)]
     fn parse(input: ParseStream<'_>) -> parse::Result<Self> {
         let crate_path = input.parse::<Group>()?.stream();
 
         let alloc = input.parse::<LitBool>()?.value;
         let arbitrary1 = input.parse::<LitBool>()?.value;
+        let borsh1 = input.parse::<LitBool>()?.value;
         let bytemuck1 = input.parse::<LitBool>()?.value;
+        let clap4 = input.parse::<LitBool>()?.value;
+        let defmt1 = input.parse::<LitBool>()?.value;
+        let pyo3028 = input.parse::<LitBool>()?.value;
+        let rkyv08 = input.parse::<LitBool>()?.value;
+        let rusqlite040 = input.parse::<LitBool>()?.value;
+        let schemars1 = input.parse::<LitBool>()?.value;
         let serde1 = input.parse::<LitBool>()?.value;
         let std = input.parse::<LitBool>()?.value;
+        let ufmt02 = input.parse::<LitBool>()?.value;
+        let utoipa5 = input.parse::<LitBool>()?.value;
         let zerocopy06 = input.parse::<LitBool>()?.value;
+        let zerocopy08 = input.parse::<LitBool>()?.value;
         let step_trait = input.parse::<LitBool>()?.value;
 
         let mut attrs = input.call(Attribute::parse_outer)?;
@@ -94,6 +119,11 @@ impl Parse for BoundedInteger {
             .map(|pos| attrs.remove(pos).parse_args::<Repr>())
             .transpose()?;
 
+        let rkyv_pos = attrs.iter().position(|attr| attr.path().is_ident("rkyv"));
+        let archived = rkyv_pos
+            .map(|pos| attrs.remove(pos).parse_args_with(parse_archived))
+            .transpose()?;
+
         let vis: Visibility = input.parse()?;
 
         let kind: Kind = input.parse()?;
@@ -105,7 +135,7 @@ impl Parse for BoundedInteger {
         let range: ExprRange = range_tokens.parse()?;
 
         let Some((start_expr, end_expr)) = range.start.as_deref().zip(range.end.as_deref()) else {
-            return Err(Error::new_spanned(range, "Range must be closed"))
+            return Err(Error::new_spanned(range, "Range must be closed"));
         };
         let start = eval_expr(start_expr)?;
         let end = eval_expr(end_expr)?;
@@ -130,7 +160,7 @@ impl Parse for BoundedInteger {
                     ));
                 }
 
-                if explicit_repr.minimum().map_or(false, |min| start < min) {
+                if explicit_repr.minimum().is_some_and(|min| start < min) {
                     return Err(Error::new_spanned(
                         start_expr,
                         format_args!(
@@ -138,7 +168,7 @@ impl Parse for BoundedInteger {
                         ),
                     ));
                 }
-                if explicit_repr.maximum().map_or(false, |max| end > max) {
+                if explicit_repr.maximum().is_some_and(|max| end > max) {
                     return Err(Error::new_spanned(
                         end_expr,
                         format_args!(
@@ -158,13 +188,24 @@ impl Parse for BoundedInteger {
             crate_path,
             alloc,
             arbitrary1,
+            borsh1,
             bytemuck1,
+            clap4,
+            defmt1,
+            pyo3028,
+            rkyv08,
+            rusqlite040,
+            schemars1,
             serde1,
             std,
+            ufmt02,
+            utoipa5,
             zerocopy06,
+            zerocopy08,
             step_trait,
             attrs,
             repr,
+            archived,
             vis,
             kind,
             ident,
@@ -174,6 +215,15 @@ impl Parse for BoundedInteger {
     }
 }
 
+fn parse_archived(input: ParseStream<'_>) -> parse::Result<Ident> {
+    let key: Ident = input.parse()?;
+    if key != "archived" {
+        return Err(Error::new_spanned(key, "expected `archived`"));
+    }
+    input.parse::<Token![=]>()?;
+    input.parse()
+}
+
 enum Kind {
     Struct(Token![struct]),
     Enum(Token![enum]),
@@ -299,10 +349,6 @@ impl Repr {
             ),
         }
     }
-
-    fn is_usize(&self) -> bool {
-        matches!((self.sign, self.size), (Unsigned, ReprSize::Pointer))
-    }
 }
 
 impl Parse for Repr {
@@ -0,0 +1,427 @@
+//! A fixed-size array indexed by bounded integers.
+
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Enumerate, FusedIterator};
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+use core::{array, slice};
+
+use crate::BoundedKey;
+
+/// A fixed-size array with one element for every value of the bounded integer `K`.
+///
+/// Because every possible key has a slot, indexing a `BoundedArray` never fails and does not
+/// perform any bounds checks.
+///
+/// Due to the limits of const generics, the length of the array `N` must be given explicitly. It
+/// must be equal to [`K::COUNT`](BoundedKey::COUNT); constructing a `BoundedArray` with any other
+/// length fails to compile:
+///
+/// ```compile_fail
+#[cfg_attr(not(feature = "types"), doc = "# compile_error!(\"\");")]
+/// # use bounded_integer::{BoundedArray, BoundedU8};
+/// let array = BoundedArray::<BoundedU8<1, 7>, u32, 8>::default();
+/// ```
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// # use bounded_integer::{BoundedArray, BoundedU8};
+/// type Weekday = BoundedU8<1, 7>;
+///
+/// let mut hours = BoundedArray::<Weekday, u32, 7>::from_fn(|day| u32::from(day.get()));
+/// hours[Weekday::new(3).unwrap()] += 5;
+///
+/// assert_eq!(hours[Weekday::MIN], 1);
+/// assert_eq!(hours[Weekday::new(3).unwrap()], 8);
+/// assert_eq!(hours.values().sum::<u32>(), 33);
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+pub struct BoundedArray<K, V, const N: usize> {
+    array: [V; N],
+    key: PhantomData<K>,
+}
+
+impl<K: BoundedKey, V, const N: usize> BoundedArray<K, V, N> {
+    const CHECK_LEN: () = assert!(N == K::COUNT, "length of array must be equal to `K::COUNT`");
+
+    /// Creates a bounded array from an array whose elements correspond to the keys in increasing
+    /// order.
+    #[must_use]
+    #[inline]
+    pub fn new(array: [V; N]) -> Self {
+        let () = Self::CHECK_LEN;
+        Self {
+            array,
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a bounded array by calling `f` with each key in increasing order.
+    #[must_use]
+    pub fn from_fn<F: FnMut(K) -> V>(mut f: F) -> Self {
+        Self::new(array::from_fn(|i| {
+            // SAFETY: `i < N` and we check that `N == K::COUNT` in `new`.
+            f(unsafe { K::from_index_unchecked(i) })
+        }))
+    }
+
+    /// Returns a shared reference to the element corresponding to the given key.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: K) -> &V {
+        // SAFETY: `to_index` is always less than `K::COUNT`, which is equal to `N`.
+        unsafe { self.array.get_unchecked(key.to_index()) }
+    }
+
+    /// Returns a mutable reference to the element corresponding to the given key.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> &mut V {
+        // SAFETY: `to_index` is always less than `K::COUNT`, which is equal to `N`.
+        unsafe { self.array.get_unchecked_mut(key.to_index()) }
+    }
+
+    /// Returns an iterator over the keys and shared references to their elements.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.array.iter().enumerate(),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the keys and mutable references to their elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.array.iter_mut().enumerate(),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over all the keys in increasing order.
+    #[inline]
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = K> + ExactSizeIterator {
+        // SAFETY: `i < N`, which is equal to `K::COUNT`.
+        (0..N).map(|i| unsafe { K::from_index_unchecked(i) })
+    }
+
+    /// Returns an iterator over shared references to the elements.
+    #[inline]
+    pub fn values(&self) -> slice::Iter<'_, V> {
+        self.array.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    #[inline]
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, V> {
+        self.array.iter_mut()
+    }
+
+    /// Creates a bounded array of the same keys by applying `f` to each element.
+    #[must_use]
+    pub fn map<U, F: FnMut(V) -> U>(self, f: F) -> BoundedArray<K, U, N> {
+        BoundedArray::new(self.array.map(f))
+    }
+
+    /// Returns a shared reference to the underlying array.
+    #[must_use]
+    #[inline]
+    pub const fn as_array(&self) -> &[V; N] {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the underlying array.
+    #[must_use]
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [V; N] {
+        &mut self.array
+    }
+
+    /// Converts the bounded array into the underlying array.
+    #[must_use]
+    #[inline]
+    pub fn into_array(self) -> [V; N] {
+        self.array
+    }
+}
+
+impl<K: BoundedKey, V, const N: usize> Index<K> for BoundedArray<K, V, N> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: K) -> &Self::Output {
+        self.get(key)
+    }
+}
+
+impl<K: BoundedKey, V, const N: usize> IndexMut<K> for BoundedArray<K, V, N> {
+    #[inline]
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.get_mut(key)
+    }
+}
+
+impl<K: BoundedKey, V: Default, const N: usize> Default for BoundedArray<K, V, N> {
+    fn default() -> Self {
+        Self::from_fn(|_| V::default())
+    }
+}
+
+impl<K, V: Clone, const N: usize> Clone for BoundedArray<K, V, N> {
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            key: PhantomData,
+        }
+    }
+}
+
+impl<K, V: Copy, const N: usize> Copy for BoundedArray<K, V, N> {}
+
+impl<K, V: PartialEq, const N: usize> PartialEq for BoundedArray<K, V, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.array == other.array
+    }
+}
+
+impl<K, V: Eq, const N: usize> Eq for BoundedArray<K, V, N> {}
+
+impl<K, V: core::hash::Hash, const N: usize> core::hash::Hash for BoundedArray<K, V, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.array.hash(state);
+    }
+}
+
+impl<K: BoundedKey + Debug, V: Debug, const N: usize> Debug for BoundedArray<K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: BoundedKey, V, const N: usize> IntoIterator for &'a BoundedArray<K, V, N> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: BoundedKey, V, const N: usize> IntoIterator for &'a mut BoundedArray<K, V, N> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: BoundedKey, V, const N: usize> IntoIterator for BoundedArray<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.array.into_iter().enumerate(),
+            key: PhantomData,
+        }
+    }
+}
+
+macro_rules! iterator {
+    ($(#[$attr:meta])* $name:ident[$($generics:tt)*][$($args:tt)*], $inner:ty, $item:ty) => {
+        $(#[$attr])*
+        pub struct $name<$($generics)*> {
+            inner: Enumerate<$inner>,
+            key: PhantomData<K>,
+        }
+
+        impl<$($generics)*> Iterator for $name<$($args)*>
+        where
+            K: BoundedKey,
+        {
+            type Item = (K, $item);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (i, v) = self.inner.next()?;
+                // SAFETY: The iterator is over an array of length `K::COUNT`.
+                Some((unsafe { K::from_index_unchecked(i) }, v))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<$($generics)*> DoubleEndedIterator for $name<$($args)*>
+        where
+            K: BoundedKey,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let (i, v) = self.inner.next_back()?;
+                // SAFETY: The iterator is over an array of length `K::COUNT`.
+                Some((unsafe { K::from_index_unchecked(i) }, v))
+            }
+        }
+
+        impl<$($generics)*> ExactSizeIterator for $name<$($args)*> where K: BoundedKey {}
+        impl<$($generics)*> FusedIterator for $name<$($args)*> where K: BoundedKey {}
+    };
+}
+
+iterator! {
+    /// An iterator over the keys and shared references to the elements of a [`BoundedArray`].
+    ///
+    /// This is created by [`BoundedArray::iter`].
+    Iter['a, K, V]['a, K, V], slice::Iter<'a, V>, &'a V
+}
+
+iterator! {
+    /// An iterator over the keys and mutable references to the elements of a [`BoundedArray`].
+    ///
+    /// This is created by [`BoundedArray::iter_mut`].
+    IterMut['a, K, V]['a, K, V], slice::IterMut<'a, V>, &'a mut V
+}
+
+iterator! {
+    /// An owning iterator over the keys and elements of a [`BoundedArray`].
+    ///
+    /// This is created by the [`IntoIterator`] implementation of [`BoundedArray`].
+    IntoIter[K, V, const N: usize][K, V, N], array::IntoIter<V, N>, V
+}
+
+// === Serde ===
+
+#[cfg(feature = "serde1")]
+mod serde {
+    use core::fmt::{self, Formatter};
+    use core::marker::PhantomData;
+
+    use serde1::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde1::ser::{Serialize, SerializeTuple, Serializer};
+
+    use super::BoundedArray;
+    use crate::BoundedKey;
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde1")))]
+    impl<K, V: Serialize, const N: usize> Serialize for BoundedArray<K, V, N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut tuple = serializer.serialize_tuple(N)?;
+            for value in &self.array {
+                tuple.serialize_element(value)?;
+            }
+            tuple.end()
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde1")))]
+    impl<'de, K: BoundedKey, V: Deserialize<'de>, const N: usize> Deserialize<'de>
+        for BoundedArray<K, V, N>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct ArrayVisitor<K, V, const N: usize>(PhantomData<(K, V)>);
+
+            impl<'de, K: BoundedKey, V: Deserialize<'de>, const N: usize> Visitor<'de>
+                for ArrayVisitor<K, V, N>
+            {
+                type Value = BoundedArray<K, V, N>;
+
+                fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, "an array of length {N}")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let mut error = None;
+                    let array: [Option<V>; N] = core::array::from_fn(|i| {
+                        if error.is_some() {
+                            return None;
+                        }
+                        match seq.next_element() {
+                            Ok(Some(value)) => Some(value),
+                            Ok(None) => {
+                                error = Some(de::Error::invalid_length(i, &self));
+                                None
+                            }
+                            Err(e) => {
+                                error = Some(e);
+                                None
+                            }
+                        }
+                    });
+                    if let Some(error) = error {
+                        return Err(error);
+                    }
+                    Ok(BoundedArray::new(array.map(|value| value.unwrap())))
+                }
+            }
+
+            deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "types", feature = "macro"))]
+mod tests {
+    use super::BoundedArray;
+
+    #[test]
+    #[cfg(feature = "types")]
+    fn index() {
+        type Key = crate::BoundedI8<-2, 2>;
+        let mut array = BoundedArray::<Key, i32, 5>::from_fn(|key| i32::from(key.get()) * 10);
+
+        assert_eq!(array[Key::MIN], -20);
+        assert_eq!(array[Key::new(0).unwrap()], 0);
+        assert_eq!(array[Key::MAX], 20);
+
+        array[Key::new(1).unwrap()] += 1;
+        assert_eq!(*array.get(Key::new(1).unwrap()), 11);
+        assert_eq!(array.into_array(), [-20, -10, 0, 11, 20]);
+    }
+
+    #[test]
+    #[cfg(feature = "types")]
+    fn iter() {
+        type Key = crate::BoundedU8<250, 255>;
+        let mut array = BoundedArray::<Key, u8, 6>::default();
+
+        for (key, value) in &mut array {
+            *value = key.get() - 250;
+        }
+        assert!(array.iter().all(|(key, &value)| key.get() - 250 == value));
+        assert_eq!(array.iter().next_back(), Some((Key::MAX, &5)));
+        assert_eq!(array.iter().len(), 6);
+        assert!(array.keys().eq((250..=255).map(|n| Key::new(n).unwrap())));
+
+        let mapped = array.map(|value| value * 2);
+        assert!(mapped.values().eq(&[0, 2, 4, 6, 8, 10]));
+        assert!(mapped
+            .into_iter()
+            .eq(array.iter().map(|(k, &v)| (k, v * 2))));
+    }
+
+    #[test]
+    #[cfg(feature = "macro")]
+    fn macro_key() {
+        crate::bounded_integer! {
+            enum Key { -1..=1 }
+        }
+
+        let array = BoundedArray::<Key, &str, 3>::new(["low", "mid", "high"]);
+        assert_eq!(array[Key::N1], "low");
+        assert_eq!(array[Key::Z], "mid");
+        assert_eq!(array[Key::P1], "high");
+        assert!(array.keys().eq([Key::N1, Key::Z, Key::P1]));
+    }
+}
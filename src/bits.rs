@@ -0,0 +1,262 @@
+//! Packing bounded integers into the minimum number of bits.
+
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// A bounded integer that can be stored in as few bits as its range allows.
+///
+/// This is implemented for all the bounded integers in this crate, both macro-generated and
+/// const-generic. Values are stored as their distance from the type's minimum value, so a
+/// `BoundedU16<1000, 1015>` only needs 4 bits. [`BitWriter`] and [`BitReader`] use this trait to
+/// pack sequences of different bounded integers into bytes.
+pub trait BoundedBits: Copy {
+    /// The distance of the maximum value from the minimum value.
+    const MAX_OFFSET: u128;
+
+    /// The number of bits needed to store the distance of any value from the minimum value.
+    const BITS: u32 = u128::BITS - Self::MAX_OFFSET.leading_zeros();
+
+    /// Returns the distance of this value from the minimum value, which fits in
+    /// [`BITS`](Self::BITS) bits.
+    #[must_use]
+    fn to_offset_bits(self) -> u128;
+
+    /// Creates a value from its distance from the minimum value, if that is in range.
+    #[must_use]
+    fn from_offset_bits(bits: u128) -> Option<Self>;
+}
+
+/// Writes bounded integers into a byte buffer, using [`BITS`](BoundedBits::BITS) bits for each.
+///
+/// Values are written least significant bit first, starting at the least significant bit of the
+/// first byte. Bits that aren't written to are left unchanged.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// use bounded_integer::{BitReader, BitWriter, BoundedBits, BoundedI8, BoundedU16};
+///
+/// type Channel = BoundedU16<1000, 1015>;
+/// type Temperature = BoundedI8<-20, 50>;
+/// assert_eq!(Channel::BITS, 4);
+/// assert_eq!(Temperature::BITS, 7);
+///
+/// let mut buf = [0; 2];
+/// let mut writer = BitWriter::new(&mut buf);
+/// writer.write(Channel::new(1013).unwrap()).unwrap();
+/// writer.write(Temperature::new(-5).unwrap()).unwrap();
+/// assert_eq!(writer.byte_len(), 2);
+/// assert!(writer.write(Temperature::MIN).is_err());
+///
+/// let mut reader = BitReader::new(&buf);
+/// assert_eq!(reader.read::<Channel>().unwrap(), 1013);
+/// assert_eq!(reader.read::<Temperature>().unwrap(), -5);
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buf: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a writer that starts at the beginning of the buffer.
+    #[must_use]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, position: 0 }
+    }
+
+    /// Writes a bounded integer.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is not enough space left in the buffer, in which case nothing is written.
+    pub fn write<T: BoundedBits>(&mut self, value: T) -> Result<(), BufferTooShort> {
+        self.write_bits(value.to_offset_bits(), T::BITS)
+    }
+
+    /// Writes the lowest `len` bits of `bits`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is not enough space left in the buffer, in which case nothing is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than 128.
+    pub fn write_bits(&mut self, mut bits: u128, len: u32) -> Result<(), BufferTooShort> {
+        assert!(len <= u128::BITS, "cannot write more than 128 bits at once");
+        if self.remaining_bits() < len as usize {
+            return Err(BufferTooShort);
+        }
+
+        let mut len = len;
+        while len > 0 {
+            let byte = &mut self.buf[self.position / 8];
+            let shift = (self.position % 8) as u32;
+            let taken = len.min(8 - shift);
+            let mask = (((1_u16 << taken) - 1) as u8) << shift;
+
+            *byte = (*byte & !mask) | (((bits as u8) << shift) & mask);
+
+            bits >>= taken;
+            len -= taken;
+            self.position += taken as usize;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bits written so far.
+    #[must_use]
+    pub fn bit_position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes that have been written to, including a partially written last
+    /// byte.
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        self.position.div_ceil(8)
+    }
+
+    /// Returns the number of bits that can still be written.
+    #[must_use]
+    pub fn remaining_bits(&self) -> usize {
+        self.buf.len() * 8 - self.position
+    }
+}
+
+/// Reads bounded integers written by a [`BitWriter`] from a byte buffer.
+///
+/// See [`BitWriter`] for an example.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    buf: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a reader that starts at the beginning of the buffer.
+    #[must_use]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, position: 0 }
+    }
+
+    /// Reads a bounded integer.
+    ///
+    /// Returns [`None`] if there are not enough bits left in the buffer or the bits read are out
+    /// of the range of `T`, in which case the reader does not advance.
+    pub fn read<T: BoundedBits>(&mut self) -> Option<T> {
+        let start = self.position;
+        let value = self.read_bits(T::BITS).and_then(T::from_offset_bits);
+        if value.is_none() {
+            self.position = start;
+        }
+        value
+    }
+
+    /// Reads `len` bits.
+    ///
+    /// Returns [`None`] if there are not enough bits left in the buffer, in which case the reader
+    /// does not advance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than 128.
+    pub fn read_bits(&mut self, len: u32) -> Option<u128> {
+        assert!(len <= u128::BITS, "cannot read more than 128 bits at once");
+        if self.remaining_bits() < len as usize {
+            return None;
+        }
+
+        let mut bits = 0;
+        let mut read = 0;
+        while read < len {
+            let byte = self.buf[self.position / 8];
+            let shift = (self.position % 8) as u32;
+            let taken = (len - read).min(8 - shift);
+            let chunk = (byte >> shift) & (((1_u16 << taken) - 1) as u8);
+
+            bits |= u128::from(chunk) << read;
+
+            read += taken;
+            self.position += taken as usize;
+        }
+        Some(bits)
+    }
+
+    /// Returns the number of bits read so far.
+    #[must_use]
+    pub fn bit_position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bits that can still be read.
+    #[must_use]
+    pub fn remaining_bits(&self) -> usize {
+        self.buf.len() * 8 - self.position
+    }
+}
+
+/// The error returned when a [`BitWriter`] runs out of space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BufferTooShort;
+
+impl Display for BufferTooShort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("not enough space left in the buffer")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl Error for BufferTooShort {}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitReader, BitWriter, BufferTooShort};
+
+    #[test]
+    fn raw_bits() {
+        let mut buf = [0xFF; 4];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write_bits(0b101, 3).unwrap();
+        writer.write_bits(0, 0).unwrap();
+        writer.write_bits(0x2A5, 10).unwrap();
+        assert_eq!(writer.bit_position(), 13);
+        assert_eq!(writer.byte_len(), 2);
+        assert_eq!(writer.write_bits(0, 20), Err(BufferTooShort));
+        writer.write_bits(0x7_FFFF, 19).unwrap();
+        assert_eq!(writer.remaining_bits(), 0);
+        assert_eq!(buf, [0x2D, 0xF5, 0xFF, 0xFF]);
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(reader.read_bits(3), Some(0b101));
+        assert_eq!(reader.read_bits(10), Some(0x2A5));
+        assert_eq!(reader.read_bits(20), None);
+        assert_eq!(reader.bit_position(), 13);
+        assert_eq!(reader.read_bits(19), Some(0x7_FFFF));
+        assert_eq!(reader.read_bits(0), Some(0));
+        assert_eq!(reader.read_bits(1), None);
+    }
+
+    #[test]
+    fn wide() {
+        let mut buf = [0; 33];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write_bits(1, 1).unwrap();
+        writer.write_bits(u128::MAX - 1, 128).unwrap();
+        writer.write_bits(u128::MAX, 128).unwrap();
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(reader.read_bits(1), Some(1));
+        assert_eq!(reader.read_bits(128), Some(u128::MAX - 1));
+        assert_eq!(reader.read_bits(128), Some(u128::MAX));
+        assert_eq!(reader.remaining_bits(), 7);
+    }
+}
@@ -0,0 +1,29 @@
+//! Helpers shared by the implementations of borsh's traits.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::vec;
+use borsh1::io;
+use borsh1::schema::{add_definition, Declaration, Definition, Fields};
+use borsh1::BorshSchema;
+use core::fmt::Display;
+
+/// The error returned when deserializing an out-of-range bounded integer.
+pub fn out_of_range() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "integer out of range")
+}
+
+/// The declaration of a bounded integer, which includes its range, e.g. `BoundedU8<1, 7>`.
+pub fn declaration(name: &str, min: impl Display, max: impl Display) -> Declaration {
+    format!("{name}<{min}, {max}>")
+}
+
+/// Adds the definition of a bounded integer, which is a tuple struct of its inner integer.
+pub fn add_definitions<Inner: BorshSchema>(
+    declaration: Declaration,
+    definitions: &mut BTreeMap<Declaration, Definition>,
+) {
+    let fields = Fields::UnnamedFields(vec![Inner::declaration()]);
+    add_definition(declaration, Definition::Struct { fields }, definitions);
+    Inner::add_definitions_recursively(definitions);
+}
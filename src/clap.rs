@@ -0,0 +1,90 @@
+//! The value parser used by the implementations of clap's [`ValueParserFactory`].
+//!
+//! [`ValueParserFactory`]: clap4::builder::ValueParserFactory
+
+use crate::ParseError;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use clap4::builder::{PossibleValue, TypedValueParser};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use std::ffi::OsStr;
+
+/// A bounded integer that can be parsed from a command-line argument.
+#[doc(hidden)]
+pub trait Parse: FromStr<Err = ParseError> + Clone + Send + Sync + 'static {
+    /// The type of the bounds.
+    type Inner: Display;
+    /// The smallest value.
+    const MIN: Self::Inner;
+    /// The largest value.
+    const MAX: Self::Inner;
+    /// The values to list in the help text, or none to not list them.
+    const POSSIBLE_VALUES: &'static [&'static str] = &[];
+}
+
+/// A clap value parser for a bounded integer.
+///
+/// This is the [`ValueParserFactory::Parser`] of every bounded integer, which lets them be used as
+/// arguments without specifying a parser. Values that are out of range are rejected with an error
+/// that gives the range, and the values of macro-generated enums of up to 16 variants are listed
+/// in the help text.
+///
+/// [`ValueParserFactory::Parser`]: clap4::builder::ValueParserFactory::Parser
+pub struct BoundedValueParser<T>(PhantomData<fn() -> T>);
+
+impl<T> BoundedValueParser<T> {
+    /// Creates a new value parser.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for BoundedValueParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for BoundedValueParser<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BoundedValueParser<T> {}
+
+impl<T> Debug for BoundedValueParser<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("BoundedValueParser")
+    }
+}
+
+impl<T: Parse> TypedValueParser for BoundedValueParser<T> {
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap4::Command,
+        arg: Option<&clap4::Arg>,
+        value: &OsStr,
+    ) -> Result<T, clap4::Error> {
+        // Closures get clap's formatting of validation errors, which is not otherwise public.
+        TypedValueParser::parse_ref(&parse::<T>, cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        if T::POSSIBLE_VALUES.is_empty() {
+            return None;
+        }
+        Some(Box::new(T::POSSIBLE_VALUES.iter().map(PossibleValue::new)))
+    }
+}
+
+fn parse<T: Parse>(s: &str) -> Result<T, String> {
+    s.parse()
+        .map_err(|e| format!("{e}, expected an integer in {}..={}", T::MIN, T::MAX))
+}
@@ -152,17 +152,502 @@ mod tests {
         };
     }
 
+    macro_rules! test_bytes {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                use crate::ParseErrorKind::*;
+
+                assert_eq!($bounded::MIN.to_le_bytes(), [0xF8]);
+                assert_eq!($bounded::MAX.to_be_bytes(), [0x07]);
+                assert_eq!($bounded::new(-1).unwrap().to_ne_bytes(), [0xFF]);
+                assert_eq!($bounded::try_from_le_bytes([0xF8]).unwrap(), $bounded::MIN);
+                assert_eq!($bounded::try_from_be_bytes([0x07]).unwrap(), $bounded::MAX);
+                assert_eq!($bounded::try_from_ne_bytes([0x00]).unwrap().get(), 0);
+
+                assert_eq!(
+                    $bounded::try_from_le_bytes([0x08]).unwrap_err().kind(),
+                    AboveMax
+                );
+                assert_eq!(
+                    $bounded::try_from_be_bytes([0xF7]).unwrap_err().kind(),
+                    BelowMin
+                );
+            }
+        };
+    }
+
     test_range!(test_struct_range, BoundedStruct);
     test_saturating!(test_struct_saturating, BoundedStruct);
     test_arithmetic!(test_struct_arithmetic, BoundedStruct);
     test_iter!(test_struct_iter, BoundedStruct);
     test_parse!(test_struct_parse, BoundedStruct);
+    test_bytes!(test_struct_bytes, BoundedStruct);
 
     test_range!(test_enum_range, BoundedEnum);
     test_saturating!(test_enum_saturating, BoundedEnum);
     test_arithmetic!(test_enum_arithmetic, BoundedEnum);
     test_iter!(test_enum_iter, BoundedEnum);
     test_parse!(test_enum_parse, BoundedEnum);
+    test_bytes!(test_enum_bytes, BoundedEnum);
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn format() {
+        use std::format;
+
+        bounded_integer! {
+            struct Minutes { 0..60 }
+        }
+        bounded_integer! {
+            #[repr(i16)]
+            enum Enum { -300..=10 }
+        }
+
+        assert_eq!(
+            format!("{}", Minutes::new(7).unwrap().display_padded()),
+            "07"
+        );
+        assert_eq!(format!("{}", Minutes::MAX.format_radix(36)), "1n");
+        assert_eq!(format!("{}", Enum::P10.display_padded()), "010");
+        assert_eq!(format!("{}", Enum::N300.display_padded()), "-300");
+        assert_eq!(format!("{}", Enum::N300.format_radix(16)), "-12c");
+        assert_eq!(Enum::from_str_radix("-12c", 16).unwrap(), Enum::N300);
+
+        let mut buf = [0; Enum::MAX_LEN];
+        assert_eq!(Enum::N300.to_ascii(&mut buf), "-300");
+        assert_eq!(Enum::P7.to_ascii(&mut buf), "7");
+    }
+
+    #[test]
+    #[cfg(feature = "clap4")]
+    fn clap() {
+        use alloc::string::ToString;
+        use clap4::builder::TypedValueParser;
+        use clap4::{value_parser, Arg, Command};
+
+        let command = Command::new("test")
+            .arg(
+                Arg::new("struct")
+                    .long("struct")
+                    .value_parser(value_parser!(BoundedStruct)),
+            )
+            .arg(
+                Arg::new("enum")
+                    .long("enum")
+                    .value_parser(value_parser!(BoundedEnum)),
+            );
+        let args = ["test", "--struct=-8", "--enum", "7"];
+        let matches = command.clone().try_get_matches_from(args).unwrap();
+        assert_eq!(
+            matches.get_one::<BoundedStruct>("struct"),
+            Some(&BoundedStruct::MIN)
+        );
+        assert_eq!(
+            matches.get_one::<BoundedEnum>("enum"),
+            Some(&BoundedEnum::P7)
+        );
+
+        let error = command
+            .try_get_matches_from(["test", "--struct", "8"])
+            .unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("invalid value '8' for '--struct <struct>'"));
+        assert!(message.contains("number too high to fit in target range"));
+        assert!(message.contains("expected an integer in -8..=7"));
+
+        let parser = value_parser!(BoundedStruct);
+        assert!(parser.possible_values().is_none());
+        let parser = value_parser!(BoundedEnum);
+        let values = parser
+            .possible_values()
+            .unwrap()
+            .map(|v| v.get_name().to_string());
+        assert!(values.eq((-8..=7).map(|n: i8| n.to_string())));
+
+        bounded_integer! {
+            enum Large { 0..=16 }
+        }
+        assert!(value_parser!(Large).possible_values().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "defmt1")]
+    fn defmt() {
+        use defmt1::export::{fetch_bytes, make_formatter};
+        use defmt1::Format;
+
+        BoundedEnum::P3.format(make_formatter());
+        let bounded = fetch_bytes();
+        BoundedEnum::P3.get().format(make_formatter());
+        let primitive = fetch_bytes();
+
+        assert_eq!(bounded[2..], primitive[2..]);
+    }
+
+    #[test]
+    #[cfg(feature = "ufmt02")]
+    fn ufmt() {
+        use crate::ufmt_tests::{debug, display};
+
+        assert_eq!(display(&BoundedStruct::new(-5).unwrap()).as_str(), "-5");
+        assert_eq!(debug(&BoundedEnum::P3).as_str(), "3");
+    }
+
+    #[test]
+    fn slices() {
+        let mut inner = [-8, 0, 7];
+        let bounded = BoundedStruct::new_slice(&inner).unwrap();
+        assert_eq!(
+            bounded,
+            [
+                BoundedStruct::MIN,
+                BoundedStruct::new(0).unwrap(),
+                BoundedStruct::MAX
+            ]
+        );
+        assert_eq!(BoundedStruct::as_inner_slice(bounded), inner);
+        assert_eq!(BoundedStruct::new_slice(&[0, 8]), Err(1));
+
+        BoundedEnum::new_slice_mut(&mut inner).unwrap()[1] = BoundedEnum::P3;
+        assert_eq!(inner, [-8, 3, 7]);
+
+        let array = BoundedEnum::new_array(inner).unwrap();
+        assert_eq!(array, [BoundedEnum::N8, BoundedEnum::P3, BoundedEnum::P7]);
+        assert_eq!(BoundedEnum::as_inner_array(&array), &inner);
+        assert_eq!(BoundedEnum::new_array_ref(&[0, -9]), Err(1));
+        assert!(BoundedStruct::new_array_mut(&mut inner).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh1")]
+    fn borsh() {
+        use borsh1::BorshSchema;
+
+        let bytes = borsh1::to_vec(&(BoundedStruct::MIN, BoundedEnum::P7)).unwrap();
+        assert_eq!(bytes, [0xF8, 0x07]);
+        assert_eq!(
+            borsh1::from_slice::<(BoundedStruct, BoundedEnum)>(&bytes).unwrap(),
+            (BoundedStruct::MIN, BoundedEnum::P7),
+        );
+        let error = borsh1::from_slice::<BoundedEnum>(&[0x08]).unwrap_err();
+        assert_eq!(error.kind(), borsh1::io::ErrorKind::InvalidData);
+
+        assert_eq!(BoundedStruct::declaration(), "BoundedStruct<-8, 7>");
+        assert_eq!(BoundedEnum::declaration(), "BoundedEnum<-8, 7>");
+        let container = borsh1::schema_container_of::<BoundedEnum>();
+        assert!(container.get_definition("i8").is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck1")]
+    fn bytemuck() {
+        use bytemuck1::checked::{self, CheckedCastError};
+
+        let bytes = [0xF8, 0x00, 0x07];
+        let structs = checked::try_cast_slice::<u8, BoundedStruct>(&bytes).unwrap();
+        assert_eq!(
+            structs,
+            [
+                BoundedStruct::MIN,
+                BoundedStruct::new(0).unwrap(),
+                BoundedStruct::MAX
+            ]
+        );
+        assert_eq!(
+            checked::cast_slice::<u8, BoundedEnum>(&bytes)[2],
+            BoundedEnum::P7
+        );
+        assert_eq!(bytemuck1::bytes_of(&BoundedEnum::N8), [0xF8]);
+        assert_eq!(
+            checked::try_cast_slice::<u8, BoundedEnum>(&[0x08]),
+            Err(CheckedCastError::InvalidBitPattern),
+        );
+    }
+
+    #[test]
+    fn bits() {
+        use crate::{BitReader, BitWriter, BoundedBits};
+
+        assert_eq!(BoundedStruct::BITS, 4);
+        assert_eq!(BoundedEnum::BITS, 4);
+        assert_eq!(BoundedStruct::MIN.to_offset_bits(), 0);
+        assert_eq!(BoundedEnum::P7.to_offset_bits(), 15);
+        assert_eq!(BoundedStruct::from_offset_bits(8).unwrap().get(), 0);
+        assert_eq!(BoundedEnum::from_offset_bits(16), None);
+
+        bounded_integer! {
+            enum Pair { 5..=6 }
+        }
+        assert_eq!(Pair::BITS, 1);
+
+        let mut buf = [0; 2];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write(BoundedStruct::new(-3).unwrap()).unwrap();
+        writer.write(Pair::P6).unwrap();
+        writer.write(BoundedEnum::P2).unwrap();
+        assert_eq!(writer.byte_len(), 2);
+        assert_eq!(buf, [0x55, 0x01]);
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(reader.read::<BoundedStruct>().unwrap(), -3);
+        assert_eq!(reader.read::<Pair>().unwrap(), Pair::P6);
+        assert_eq!(reader.read::<BoundedEnum>().unwrap(), BoundedEnum::P2);
+    }
+
+    #[test]
+    fn pack() {
+        use crate::{BoundedBits, Pack};
+
+        assert_eq!(BoundedStruct::MAX_OFFSET, 15);
+        assert_eq!(BoundedEnum::MAX_OFFSET, 15);
+
+        bounded_integer! {
+            enum Digit { 0..10 }
+        }
+        type T = (BoundedStruct, Digit, BoundedEnum);
+        assert_eq!(T::MAX_PACKED, 16 * 10 * 16 - 1);
+
+        let value = (BoundedStruct::new(-3).unwrap(), Digit::P7, BoundedEnum::N8);
+        let packed: u16 = value.pack();
+        assert_eq!(packed, (5 * 10 + 7) * 16);
+        assert_eq!(T::unpack(packed), Some(value));
+        assert_eq!(T::unpack(16 * 10 * 16_u16), None);
+    }
+
+    #[test]
+    #[cfg(feature = "__test_pyo3028")]
+    fn pyo3() {
+        use alloc::string::ToString;
+        use pyo3028::exceptions::{PyOverflowError, PyValueError};
+        use pyo3028::types::PyAnyMethods;
+        use pyo3028::{IntoPyObject, Python};
+
+        Python::attach(|py| {
+            let obj = (BoundedStruct::MIN, BoundedEnum::P7)
+                .into_pyobject(py)
+                .unwrap();
+            assert_eq!(obj.to_string(), "(-8, 7)");
+            let values = obj.extract::<(BoundedStruct, BoundedEnum)>().unwrap();
+            assert_eq!(values, (BoundedStruct::MIN, BoundedEnum::P7));
+
+            let obj = 8_i8.into_pyobject(py).unwrap();
+            let error = obj.extract::<BoundedEnum>().unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            assert_eq!(error.value(py).to_string(), "8 is not in -8..=7");
+
+            let obj = 128_i32.into_pyobject(py).unwrap();
+            let error = obj.extract::<BoundedStruct>().unwrap_err();
+            assert!(error.is_instance_of::<PyOverflowError>(py));
+            assert_eq!(error.value(py).to_string(), "128 is not in -8..=7");
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "rkyv08")]
+    fn rkyv() {
+        use rkyv08::rancor::Error;
+
+        let values = (BoundedStruct::MIN, BoundedEnum::P7);
+        let bytes = rkyv08::to_bytes::<Error>(&[values.0.get(), values.1.get()]).unwrap();
+        assert_eq!(&*bytes, [0xF8, 0x07]);
+        let archived =
+            rkyv08::access::<[rkyv08::Archived<BoundedStruct>; 2], Error>(&bytes).unwrap();
+        assert_eq!(archived[0], BoundedStruct::MIN);
+        assert_eq!(archived[1], 7);
+        assert!(archived[0] < archived[1]);
+        let bytes = rkyv08::to_bytes::<Error>(&values.1).unwrap();
+        let archived = rkyv08::access::<rkyv08::Archived<BoundedEnum>, Error>(&bytes).unwrap();
+        assert_eq!(archived.get_bounded(), BoundedEnum::P7);
+        assert_eq!(
+            rkyv08::deserialize::<BoundedEnum, Error>(archived).unwrap(),
+            BoundedEnum::P7,
+        );
+        assert!(rkyv08::access::<rkyv08::Archived<BoundedEnum>, Error>(&[0x08]).is_err());
+
+        bounded_integer! {
+            #[repr(isize)]
+            #[rkyv(archived = ArchivedPointer)]
+            struct Pointer { -2..=2 }
+        }
+        let bytes = rkyv08::to_bytes::<Error>(&Pointer::MIN).unwrap();
+        let archived = rkyv08::access::<ArchivedPointer, Error>(&bytes).unwrap();
+        assert_eq!(*archived, -2);
+        let bytes = rkyv08::to_bytes::<Error>(&3_isize).unwrap();
+        assert!(rkyv08::access::<ArchivedPointer, Error>(&bytes).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "__test_rusqlite040")]
+    fn rusqlite() {
+        use rusqlite040::{Connection, Error};
+
+        let db = Connection::open_in_memory().unwrap();
+        db.execute("CREATE TABLE t (a INTEGER, b INTEGER)", ())
+            .unwrap();
+        let values = (BoundedStruct::MIN, BoundedEnum::P7);
+        db.execute("INSERT INTO t VALUES (?1, ?2), (8, 'seven')", values)
+            .unwrap();
+        let mut statement = db.prepare("SELECT a, b FROM t").unwrap();
+        let mut rows = statement.query(()).unwrap();
+        let row = rows.next().unwrap().unwrap();
+        assert_eq!(
+            <(BoundedStruct, BoundedEnum)>::try_from(row).unwrap(),
+            values
+        );
+        let row = rows.next().unwrap().unwrap();
+        assert!(matches!(
+            row.get::<_, BoundedStruct>(0),
+            Err(Error::IntegralValueOutOfRange(0, 8))
+        ));
+        assert!(matches!(
+            row.get::<_, BoundedEnum>(1),
+            Err(Error::InvalidColumnType(1, _, _))
+        ));
+
+        let error = db
+            .execute(
+                "INSERT INTO t VALUES (?1, 0)",
+                (crate::BoundedU64::<0, { u64::MAX }>::MAX,),
+            )
+            .unwrap_err();
+        assert!(matches!(error, Error::ToSqlConversionFailure(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "schemars1")]
+    fn schemars() {
+        use serde_json1::json;
+
+        let schema = schemars1::schema_for!(BoundedStruct);
+        assert_eq!(schema.get("title"), Some(&json!("BoundedStruct")));
+        assert_eq!(schema.get("format"), Some(&json!("int8")));
+        assert_eq!(schema.get("minimum"), Some(&json!(-8)));
+        assert_eq!(schema.get("maximum"), Some(&json!(7)));
+        assert_eq!(schema.get("enum"), None);
+
+        let schema = schemars1::schema_for!(BoundedEnum);
+        assert_eq!(
+            schema.get("enum"),
+            Some(&json!((-8..=7).collect::<alloc::vec::Vec<_>>()))
+        );
+
+        #[derive(schemars1::JsonSchema)]
+        #[schemars(crate = "schemars1")]
+        #[allow(dead_code)]
+        struct Config {
+            level: BoundedEnum,
+            port: crate::BoundedU16<1, 1000>,
+        }
+        let schema = schemars1::schema_for!(Config);
+        let port = json!({ "type": "integer", "format": "uint16", "minimum": 1, "maximum": 1000 });
+        assert_eq!(schema.get("properties").unwrap()["port"], port);
+        let level = &schema.get("$defs").unwrap()["BoundedEnum"];
+        assert_eq!(level["minimum"], -8);
+    }
+
+    #[test]
+    #[cfg(feature = "utoipa5")]
+    fn utoipa() {
+        use serde_json1::json;
+        use utoipa5::{PartialSchema, ToSchema};
+
+        let schema = serde_json1::to_value(BoundedStruct::schema()).unwrap();
+        assert_eq!(schema["minimum"], -8);
+        assert_eq!(schema["maximum"], 7);
+        assert_eq!(schema.get("enum"), None);
+        assert_eq!(BoundedStruct::name(), "BoundedStruct");
+
+        let schema = serde_json1::to_value(BoundedEnum::schema()).unwrap();
+        assert_eq!(
+            schema["enum"],
+            json!((-8..=7).collect::<alloc::vec::Vec<_>>())
+        );
+        assert_eq!(crate::BoundedU16::<1, 1000>::name(), "BoundedU16_1_1000");
+    }
+
+    #[test]
+    #[cfg(feature = "serde1")]
+    fn serde() {
+        use serde1::de::value::{Error, I64Deserializer};
+
+        let n = |n: i64| I64Deserializer::<Error>::new(n);
+        let value: BoundedStruct = crate::serde::saturating::deserialize(n(100)).unwrap();
+        assert_eq!(value, BoundedStruct::MAX);
+        let value: BoundedEnum = crate::serde::saturating::deserialize(n(-300)).unwrap();
+        assert_eq!(value, BoundedEnum::N8);
+        let value: BoundedStruct = crate::serde::wrapping::deserialize(n(8)).unwrap();
+        assert_eq!(value, BoundedStruct::MIN);
+        let value: BoundedEnum = crate::serde::wrapping::deserialize(n(-9)).unwrap();
+        assert_eq!(value, BoundedEnum::P7);
+        let value: BoundedEnum = crate::serde::offset::deserialize(n(3)).unwrap();
+        assert_eq!(value, BoundedEnum::N5);
+    }
+
+    #[test]
+    #[cfg(feature = "zerocopy08")]
+    fn zerocopy() {
+        use zerocopy08::{Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+        #[derive(Debug, PartialEq, TryFromBytes, IntoBytes, KnownLayout, Immutable)]
+        #[zerocopy(crate = "zerocopy08")]
+        #[repr(C)]
+        struct Packet {
+            kind: BoundedEnum,
+            values: [BoundedEnum; 2],
+        }
+
+        let packet = Packet::try_ref_from_bytes(&[0x07, 0xF8, 0x00]).unwrap();
+        assert_eq!(packet.kind, BoundedEnum::P7);
+        assert_eq!(packet.values, [BoundedEnum::N8, BoundedEnum::Z]);
+        assert!(Packet::try_ref_from_bytes(&[0x07, 0x08, 0x00]).is_err());
+        assert!(BoundedEnum::try_read_from_bytes(&[0xF7]).is_err());
+
+        let values = [BoundedStruct::MIN, BoundedStruct::MAX];
+        assert_eq!(values.as_bytes(), [0xF8, 0x07]);
+        assert_eq!(BoundedEnum::P7.as_bytes(), [0x07]);
+
+        bounded_integer! {
+            #[repr(u16)]
+            enum Wide { 0..3 }
+        }
+        let bytes = 2_u16.to_ne_bytes();
+        assert_eq!(Wide::try_read_from_bytes(&bytes).unwrap(), Wide::P2);
+        assert_eq!(Wide::P2.as_bytes(), bytes);
+        let bytes = 3_u16.to_ne_bytes();
+        assert!(Wide::try_read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn indexing() {
+        use crate::SliceBounded;
+
+        bounded_integer! {
+            struct Struct { 0..4 }
+        }
+        bounded_integer! {
+            #[repr(i16)]
+            enum Enum { 1..=3 }
+        }
+
+        let mut arr = [0, 1, 2, 3];
+        arr[Struct::new(2).unwrap()] += 5;
+        assert_eq!(arr[Struct::new(2).unwrap()], 7);
+        assert_eq!(arr[Enum::P3], 3);
+        assert_eq!(arr.slice_bounded(Enum::P1..Enum::P3), [1, 7]);
+        assert_eq!(arr.slice_bounded(..=Struct::new(1).unwrap()), [0, 1]);
+        assert_eq!("bounded".slice_bounded(Enum::P2..), "unded");
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut vec = alloc::vec::Vec::from(arr);
+            vec[Enum::P1] = 9;
+            assert_eq!(vec[Struct::new(1).unwrap()], 9);
+
+            let deq = alloc::collections::VecDeque::from(vec);
+            assert_eq!(deq[Enum::P2], 7);
+        }
+    }
 
     #[allow(unused_imports)]
     mod all_below_zero {
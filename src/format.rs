@@ -0,0 +1,149 @@
+use core::fmt::{self, Display, Formatter};
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+const fn check_radix(radix: u32) {
+    assert!(
+        2 <= radix && radix <= 36,
+        "radix must lie in the range `[2, 36]`",
+    );
+}
+
+/// Computes the number of digits needed to write the larger of the magnitudes `a` and `b` in the
+/// given radix.
+#[must_use]
+pub const fn max_digits(a: u128, b: u128, radix: u32) -> usize {
+    check_radix(radix);
+    let mut n = if a > b { a } else { b };
+    let mut digits = 1;
+    while n >= radix as u128 {
+        n /= radix as u128;
+        digits += 1;
+    }
+    digits
+}
+
+/// Computes the length of the longer of the decimal representations of `min` and `max`, each given
+/// as whether it is negative and its magnitude.
+#[must_use]
+#[cfg(feature = "types")]
+pub const fn max_len(min: (bool, u128), max: (bool, u128)) -> usize {
+    let min = max_digits(min.1, 0, 10) + min.0 as usize;
+    let max = max_digits(max.1, 0, 10) + max.0 as usize;
+    if min > max {
+        min
+    } else {
+        max
+    }
+}
+
+/// An integer formatted in a given radix with a minimum number of digits, returned from
+/// `display_padded` and `format_radix`.
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted {
+    negative: bool,
+    magnitude: u128,
+    radix: u32,
+    min_digits: usize,
+}
+
+impl Formatted {
+    #[must_use]
+    pub const fn new(negative: bool, magnitude: u128, radix: u32, min_digits: usize) -> Self {
+        check_radix(radix);
+        Self {
+            negative,
+            magnitude,
+            radix,
+            min_digits,
+        }
+    }
+}
+
+impl Display for Formatted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Enough for any `u128` in base 2.
+        let mut buf = [0; 128];
+        let mut start = buf.len();
+
+        let radix = u128::from(self.radix);
+        let mut n = self.magnitude;
+        loop {
+            start -= 1;
+            buf[start] = DIGITS[(n % radix) as usize];
+            n /= radix;
+            if n == 0 {
+                break;
+            }
+        }
+        while buf.len() - start < self.min_digits.min(buf.len()) {
+            start -= 1;
+            buf[start] = b'0';
+        }
+
+        // SAFETY: All the digits are ASCII.
+        let digits = unsafe { core::str::from_utf8_unchecked(&buf[start..]) };
+        f.pad_integral(!self.negative, "", digits)
+    }
+}
+
+/// The decimal representations of all numbers below 100, padded to two digits.
+const DECIMAL_PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+/// Writes the last two decimal digits of `n` before `end` in the buffer, returning `n / 100`.
+#[inline(always)]
+fn write_pair(buf: &mut [u8], end: usize, n: u64) -> u64 {
+    let pair = (n % 100) as usize * 2;
+    buf[end - 2..end].copy_from_slice(&DECIMAL_PAIRS[pair..pair + 2]);
+    n / 100
+}
+
+/// Writes an integer in decimal to the end of the buffer, returning the written part.
+///
+/// Panics if the buffer is too short.
+#[inline]
+pub fn to_ascii(negative: bool, magnitude: u128, buf: &mut [u8]) -> &str {
+    const TEN_POW_19: u128 = 10_000_000_000_000_000_000;
+
+    let mut start = buf.len();
+    let mut n = magnitude;
+
+    // Split off blocks of 19 digits until the rest fits in a `u64`, where division is much
+    // cheaper. This is compiled out for integers of 64 bits or less.
+    while n > u128::from(u64::MAX) {
+        let mut block = (n % TEN_POW_19) as u64;
+        n /= TEN_POW_19;
+        for _ in 0..9 {
+            block = write_pair(buf, start, block);
+            start -= 2;
+        }
+        start -= 1;
+        buf[start] = b'0' + block as u8;
+    }
+
+    let mut n = n as u64;
+    while n >= 100 {
+        n = write_pair(buf, start, n);
+        start -= 2;
+    }
+    if n >= 10 {
+        write_pair(buf, start, n);
+        start -= 2;
+    } else {
+        start -= 1;
+        buf[start] = b'0' + n as u8;
+    }
+
+    if negative {
+        start -= 1;
+        buf[start] = b'-';
+    }
+
+    // SAFETY: Only ASCII has been written to `buf[start..]`.
+    unsafe { core::str::from_utf8_unchecked(&buf[start..]) }
+}
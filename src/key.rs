@@ -0,0 +1,49 @@
+/// A bounded integer whose values can be numbered densely from zero.
+///
+/// This is implemented for all the bounded integers in this crate, both macro-generated and
+/// const-generic. It maps every value to its distance from the type's minimum value, which is what
+/// allows bounded integers to be used as keys of a [`BoundedArray`](crate::BoundedArray).
+///
+/// # Safety
+///
+/// [`to_index`](Self::to_index) must always return a value less than [`COUNT`](Self::COUNT), and
+/// [`from_index_unchecked`](Self::from_index_unchecked) must return the value whose index is the
+/// given one.
+pub unsafe trait BoundedKey: Copy {
+    /// The number of values this type can contain.
+    ///
+    /// Using this constant will fail to compile if the number does not fit in a `usize`.
+    const COUNT: usize;
+
+    /// Returns the index of this value; its distance from the minimum value.
+    #[must_use]
+    fn to_index(self) -> usize;
+
+    /// Creates a value from its index without checking it.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than [`COUNT`](Self::COUNT).
+    #[must_use]
+    unsafe fn from_index_unchecked(index: usize) -> Self;
+
+    /// Creates a value from its index if it is less than [`COUNT`](Self::COUNT).
+    #[must_use]
+    #[inline]
+    fn from_index(index: usize) -> Option<Self> {
+        // SAFETY: We just checked that the index is in range.
+        (index < Self::COUNT).then(|| unsafe { Self::from_index_unchecked(index) })
+    }
+}
+
+/// Computes [`BoundedKey::COUNT`] given the difference between the maximum and minimum values, or
+/// `None` if the range is empty.
+#[cfg(any(feature = "types", feature = "macro"))]
+#[must_use]
+pub const fn count(difference: Option<u128>) -> usize {
+    match difference {
+        Some(difference) if difference < usize::MAX as u128 => difference as usize + 1,
+        Some(_) => panic!("range of bounded integer is too large to fit in a `usize`"),
+        None => 0,
+    }
+}
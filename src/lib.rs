@@ -38,6 +38,28 @@
 //! and they interoperate better with other integers that have different ranges. However due to the
 //! limits of const generics, they do not implement some traits like `Default`.
 //!
+//! The unsigned ones also have atomic counterparts such as [`AtomicBoundedU16`], whose
+//! read-modify-write operations fail, saturate or wrap around instead of leaving the range.
+//!
+//! For packed structures and wire formats, types such as [`BoundedU16Be`] and [`BoundedU32Le`]
+//! store the value in a fixed byte order with an alignment of 1, checking the range on every write.
+//!
+//! # Bounded arrays
+//!
+//! [`BoundedArray`] is a fixed-size array with one element for every value of a bounded integer,
+//! which can be indexed by that bounded integer without any bounds checks or panics.
+//! [`BoundedSet`] is a set of values of a bounded integer, stored inline as a bitmap.
+//!
+//! Slices, `Vec`s and `VecDeque`s can also be indexed by non-negative bounded integers, and
+//! [`SliceBounded`] allows slicing slices and strings by ranges of them.
+//!
+//! # Packing
+//!
+//! [`BoundedBits`] gives the number of bits needed to store the values of a bounded integer, and
+//! [`BitWriter`] and [`BitReader`] use it to pack sequences of bounded integers into bytes.
+//! [`Pack`] instead packs a tuple of bounded integers into a single unsigned integer, multiplying
+//! together the number of values each one can hold, and fails to compile if they wouldn't fit.
+//!
 //! # `no_std`
 //!
 //! All the integers in this crate depend only on libcore and so work in `#![no_std]` environments.
@@ -47,32 +69,74 @@
 //! By default, no crate features are enabled.
 //! - `std`: Interopate with `std` — implies `alloc`. Enables the following things:
 //!     - An implementation of [`Error`] for [`ParseError`].
-//!     - Support for indexing with the const-generic integers on `VecDeque`.
 //! - `alloc`: Interopate with `alloc`. Enables the following things:
-//!     - Support for indexing with the const-generic integers on `Vec`.
+//!     - Support for indexing with non-negative bounded integers on `Vec` and `VecDeque`.
 //! - `macro`: Enable the [`bounded_integer!`] macro.
 //! - `types`: Enable the bounded integer types that use const generics.
 //! - `arbitrary1`: Implement [`Arbitrary`] for the bounded integers. This is useful when using
-//! bounded integers as fuzzing inputs.
-//! - `bytemuck1`: Implement [`Contiguous`] for all bounded integers, and [`Zeroable`] for
-//! macro-generated bounded integers that support it.
+//!   bounded integers as fuzzing inputs.
+//! - `borsh1`: Implement [`BorshSerialize`], [`BorshDeserialize`] and [`BorshSchema`] for the
+//!   bounded integers, making sure all values will never be out of bounds. The schema declares each
+//!   type with its range, such as `BoundedU8<1, 7>`. Implies `alloc`.
+//! - `bytemuck1`: Implement [`Contiguous`], [`NoUninit`] and [`CheckedBitPattern`] for all bounded
+//!   integers, and [`Zeroable`] for macro-generated bounded integers that support it.
+//! - `clap4`: Implement [`ValueParserFactory`] for the bounded integers, so that they can be used
+//!   as command-line arguments. Out-of-range values are rejected with an error that gives the
+//!   range, and the values of macro-generated enums of up to 16 variants are listed in the help
+//!   text. Implies `std`.
+//! - `defmt1`: Implement [`Format`] for the bounded integers, so that they can be logged with
+//!   `defmt`.
 //! - `num-traits02`: Implement [`Bounded`], [`AsPrimitive`], [`FromPrimitive`], [`NumCast`],
-//! [`ToPrimitive`], [`CheckedAdd`], [`CheckedDiv`], [`CheckedMul`], [`CheckedNeg`], [`CheckedRem`],
-//! [`CheckedSub`], [`MulAdd`], [`SaturatingAdd`], [`SaturatingMul`] and [`SaturatingSub`] for all
-//! const-generic bounded integers.
+//!   [`ToPrimitive`], [`CheckedAdd`], [`CheckedDiv`], [`CheckedMul`], [`CheckedNeg`],
+//!   [`CheckedRem`], [`CheckedSub`], [`MulAdd`], [`SaturatingAdd`], [`SaturatingMul`] and
+//!   [`SaturatingSub`] for all const-generic bounded integers.
+//! - `pyo3028`: Implement [`FromPyObject`] and [`IntoPyObject`] for the bounded integers, which
+//!   are converted to and from Python `int`s. Extracting a value that is out of range raises
+//!   `OverflowError` if it doesn't fit the inner integer and `ValueError` otherwise, giving the
+//!   range in the message. Implies `alloc`.
+//! - `rkyv08`: Implement [`Archive`], [`Serialize`][`Serialize` rkyv] and
+//!   [`Deserialize`][`Deserialize` rkyv] for the bounded integers. Each is archived as a bounded
+//!   type such as `ArchivedBoundedU16<1, 7>`, which stores the value in rkyv's portable format and
+//!   implements [`CheckBytes`] to reject values that are out of bounds. The macro generates one
+//!   for each type, which is only exported if it is named with an attribute such as
+//!   `#[rkyv(archived = ArchivedS)]`.
+//! - `rusqlite040`: Implement [`ToSql`] and [`FromSql`] for the bounded integers, which are stored
+//!   as SQLite integers. Reading an out-of-range value fails with
+//!   [`FromSqlError::OutOfRange`]. Implies `alloc`.
+//! - `schemars1`: Implement [`JsonSchema`] for the bounded integers. The schema is that of the
+//!   inner integer with `minimum` and `maximum` set to the bounds, and macro-generated enums also
+//!   list their values with `enum`. Implies `alloc`.
 //! - `serde1`: Implement [`Serialize`] and [`Deserialize`] for the bounded integers, making sure all
-//! values will never be out of bounds. This has a deprecated alias `serde`.
+//!   values will never be out of bounds, and for [`BoundedArray`] and [`BoundedSet`]. The
+//!   [`serde`] module provides alternative representations, such as a compact one that stores
+//!   just the offset from the minimum value, and ones that clamp or wrap out-of-range values
+//!   instead of failing to deserialize. This has a deprecated alias `serde`.
+//! - `ufmt02`: Implement [`uDisplay`] and [`uDebug`] for the bounded integers, which both format
+//!   just the value.
+//! - `utoipa5`: Implement [`PartialSchema`] and [`ToSchema`] for the bounded integers, with the
+//!   same schemas as `schemars1`. Implies `alloc`.
 //! - `zerocopy06`: Implement [`AsBytes`] for all bounded integers, and [`Unaligned`] for
-//! macro-generated ones.
+//!   macro-generated ones.
+//! - `zerocopy08`: Implement [`IntoBytes`], [`KnownLayout`] and [`Immutable`] for the bounded
+//!   integers, [`Unaligned`][`Unaligned` 0.8] for those stored in a single byte or in a fixed byte
+//!   order, and [`TryFromBytes`] for macro-generated enums. Bounded structs don't implement
+//!   [`TryFromBytes`], since zerocopy can't be made to check that their values are in range.
 //! - `step_trait`: Implement the [`Step`] trait which allows the bounded integers to be easily used
-//! in ranges. This will require you to use nightly and place `#![feature(step_trait)]` in your
-//! crate root if you use the macro.
+//!   in ranges. This will require you to use nightly and place `#![feature(step_trait)]` in your
+//!   crate root if you use the macro.
 //!
 //! [`bounded_integer!`]: https://docs.rs/bounded-integer/*/bounded_integer/macro.bounded_integer.html
 //! [`examples`]: https://docs.rs/bounded-integer/*/bounded_integer/examples/
 //! [`Arbitrary`]: https://docs.rs/arbitrary/1/arbitrary/trait.Arbitrary.html
+//! [`BorshSerialize`]: https://docs.rs/borsh/1/borsh/ser/trait.BorshSerialize.html
+//! [`BorshDeserialize`]: https://docs.rs/borsh/1/borsh/de/trait.BorshDeserialize.html
+//! [`BorshSchema`]: https://docs.rs/borsh/1/borsh/trait.BorshSchema.html
 //! [`Contiguous`]: https://docs.rs/bytemuck/1/bytemuck/trait.Contiguous.html
 //! [`Zeroable`]: https://docs.rs/bytemuck/1/bytemuck/trait.Zeroable.html
+//! [`NoUninit`]: https://docs.rs/bytemuck/1/bytemuck/trait.NoUninit.html
+//! [`CheckedBitPattern`]: https://docs.rs/bytemuck/1/bytemuck/checked/trait.CheckedBitPattern.html
+//! [`ValueParserFactory`]: https://docs.rs/clap/4/clap/builder/trait.ValueParserFactory.html
+//! [`Format`]: https://docs.rs/defmt/1/defmt/trait.Format.html
 //! [`Bounded`]: https://docs.rs/num-traits/0.2/num_traits/bounds/trait.Bounded.html
 //! [`AsPrimitive`]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.AsPrimitive.html
 //! [`FromPrimitive`]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.FromPrimitive.html
@@ -88,10 +152,29 @@
 //! [`SaturatingAdd`]: https://docs.rs/num-traits/0.2/num_traits/ops/saturating/trait.SaturatingAdd.html
 //! [`SaturatingMul`]: https://docs.rs/num-traits/0.2/num_traits/ops/saturating/trait.SaturatingMul.html
 //! [`SaturatingSub`]: https://docs.rs/num-traits/0.2/num_traits/ops/saturating/trait.SaturatingSub.html
+//! [`FromPyObject`]: https://docs.rs/pyo3/0.28/pyo3/conversion/trait.FromPyObject.html
+//! [`IntoPyObject`]: https://docs.rs/pyo3/0.28/pyo3/conversion/trait.IntoPyObject.html
+//! [`Archive`]: https://docs.rs/rkyv/0.8/rkyv/trait.Archive.html
+//! [`Serialize` rkyv]: https://docs.rs/rkyv/0.8/rkyv/trait.Serialize.html
+//! [`Deserialize` rkyv]: https://docs.rs/rkyv/0.8/rkyv/trait.Deserialize.html
+//! [`CheckBytes`]: https://docs.rs/bytecheck/0.8/bytecheck/trait.CheckBytes.html
+//! [`ToSql`]: https://docs.rs/rusqlite/0.40/rusqlite/types/trait.ToSql.html
+//! [`FromSql`]: https://docs.rs/rusqlite/0.40/rusqlite/types/trait.FromSql.html
+//! [`FromSqlError::OutOfRange`]: https://docs.rs/rusqlite/0.40/rusqlite/types/enum.FromSqlError.html#variant.OutOfRange
+//! [`JsonSchema`]: https://docs.rs/schemars/1/schemars/trait.JsonSchema.html
 //! [`Serialize`]: https://docs.rs/serde/1/serde/trait.Serialize.html
 //! [`Deserialize`]: https://docs.rs/serde/1/serde/trait.Deserialize.html
+//! [`uDisplay`]: https://docs.rs/ufmt/0.2/ufmt/trait.uDisplay.html
+//! [`uDebug`]: https://docs.rs/ufmt/0.2/ufmt/trait.uDebug.html
+//! [`PartialSchema`]: https://docs.rs/utoipa/5/utoipa/trait.PartialSchema.html
+//! [`ToSchema`]: https://docs.rs/utoipa/5/utoipa/trait.ToSchema.html
 //! [`AsBytes`]: https://docs.rs/zerocopy/0.6/zerocopy/trait.AsBytes.html
 //! [`Unaligned`]: https://docs.rs/zerocopy/0.6/zerocopy/trait.Unaligned.html
+//! [`IntoBytes`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.IntoBytes.html
+//! [`KnownLayout`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.KnownLayout.html
+//! [`Immutable`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.Immutable.html
+//! [`TryFromBytes`]: https://docs.rs/zerocopy/0.8/zerocopy/trait.TryFromBytes.html
+//! [`Unaligned` 0.8]: https://docs.rs/zerocopy/0.8/zerocopy/trait.Unaligned.html
 //! [`Step`]: https://doc.rust-lang.org/nightly/core/iter/trait.Step.html
 //! [`Error`]: https://doc.rust-lang.org/stable/std/error/trait.Error.html
 //! [`ParseError`]: https://docs.rs/bounded-integer/*/bounded_integer/struct.ParseError.html
@@ -111,32 +194,189 @@ mod types;
 #[cfg(feature = "types")]
 pub use types::*;
 
+#[cfg_attr(not(any(feature = "types", feature = "macro")), allow(dead_code))]
 mod parse;
 pub use parse::{ParseError, ParseErrorKind};
 
+#[cfg(all(feature = "borsh1", any(feature = "types", feature = "macro")))]
+mod borsh;
+
+#[cfg(feature = "clap4")]
+mod clap;
+#[cfg(feature = "clap4")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clap4")))]
+pub use clap::BoundedValueParser;
+
+#[cfg_attr(not(any(feature = "types", feature = "macro")), allow(dead_code))]
+mod format;
+
+#[cfg(all(feature = "pyo3028", any(feature = "types", feature = "macro")))]
+mod pyo3;
+
+#[cfg(all(feature = "rkyv08", any(feature = "types", feature = "macro")))]
+mod rkyv;
+
+#[cfg(all(feature = "rusqlite040", any(feature = "types", feature = "macro")))]
+mod rusqlite;
+
+#[cfg(all(feature = "schemars1", any(feature = "types", feature = "macro")))]
+mod schemars;
+
+#[cfg(all(feature = "utoipa5", any(feature = "types", feature = "macro")))]
+mod utoipa;
+
+mod key;
+pub use key::BoundedKey;
+
+pub mod array;
+pub use array::BoundedArray;
+
+pub mod set;
+pub use set::BoundedSet;
+
+pub mod bits;
+pub use bits::{BitReader, BitWriter, BoundedBits};
+
+pub mod pack;
+pub use pack::Pack;
+
+#[cfg(feature = "serde1")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde1")))]
+pub mod serde;
+
+mod slice;
+pub use slice::SliceBounded;
+
 #[doc(hidden)]
 #[cfg(feature = "macro")]
 pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::{collections::VecDeque, vec::Vec};
+
     #[cfg(feature = "arbitrary1")]
     pub use ::arbitrary1;
 
+    #[cfg(feature = "borsh1")]
+    pub use ::borsh1;
+    #[cfg(feature = "borsh1")]
+    pub use alloc::collections::BTreeMap;
+
     #[cfg(feature = "bytemuck1")]
     pub use ::bytemuck1;
 
+    #[cfg(feature = "clap4")]
+    pub use ::clap4;
+
+    #[cfg(feature = "defmt1")]
+    pub use ::defmt1;
+
+    #[cfg(feature = "pyo3028")]
+    pub use ::pyo3028;
+
+    #[cfg(feature = "rkyv08")]
+    pub use ::rkyv08;
+
+    #[cfg(feature = "rusqlite040")]
+    pub use ::rusqlite040;
+
+    #[cfg(feature = "schemars1")]
+    pub use ::schemars1;
+
     #[cfg(feature = "serde1")]
     pub use ::serde1;
 
+    #[cfg(feature = "ufmt02")]
+    pub use ::ufmt02;
+
+    #[cfg(feature = "utoipa5")]
+    pub use ::utoipa5;
+
     #[cfg(feature = "zerocopy06")]
     pub use ::zerocopy06;
 
+    #[cfg(feature = "zerocopy08")]
+    pub use ::zerocopy08;
+
+    #[cfg(any(feature = "schemars1", feature = "utoipa5"))]
+    pub use alloc::borrow::Cow;
+
     pub use bounded_integer_macro::bounded_integer as proc_macro;
 
+    #[cfg(feature = "borsh1")]
+    pub use crate::borsh::{
+        add_definitions as borsh_add_definitions, declaration as borsh_declaration,
+        out_of_range as borsh_out_of_range,
+    };
+    #[cfg(feature = "clap4")]
+    pub use crate::clap::Parse as ClapParse;
+    pub use crate::format::{max_digits, to_ascii, Formatted};
+    pub use crate::key::count as key_count;
     pub use crate::parse::{error_above_max, error_below_min, FromStrRadix};
+    #[cfg(feature = "pyo3028")]
+    pub use crate::pyo3::extract as pyo3_extract;
+    #[cfg(feature = "rkyv08")]
+    pub use crate::rkyv::ArchiveError as RkyvArchiveError;
+    #[cfg(feature = "rusqlite040")]
+    pub use crate::rusqlite::{from_sql as rusqlite_from_sql, to_sql as rusqlite_to_sql};
+    #[cfg(feature = "schemars1")]
+    pub use crate::schemars::{
+        list_values as schemars_list_values, name as schemars_name, schema as schemars_schema,
+    };
+    #[cfg(feature = "serde1")]
+    pub use crate::serde::Lenient as SerdeLenient;
+    pub use crate::slice::SliceIndex;
+    #[cfg(feature = "utoipa5")]
+    pub use crate::utoipa::{list_values as utoipa_list_values, schema as utoipa_schema};
 }
 
 #[cfg(feature = "__examples")]
 pub mod examples;
 
+#[cfg(all(test, feature = "ufmt02"))]
+mod ufmt_tests {
+    use core::convert::Infallible;
+
+    use ufmt02::{uDebug, uDisplay, uWrite, Formatter};
+
+    pub(crate) struct Buffer {
+        buf: [u8; 64],
+        len: usize,
+    }
+
+    impl Buffer {
+        pub(crate) fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl uWrite for Buffer {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    fn format(f: impl FnOnce(&mut Formatter<'_, Buffer>) -> Result<(), Infallible>) -> Buffer {
+        let mut buffer = Buffer {
+            buf: [0; 64],
+            len: 0,
+        };
+        f(&mut Formatter::new(&mut buffer)).unwrap();
+        buffer
+    }
+
+    pub(crate) fn display(value: &impl uDisplay) -> Buffer {
+        format(|f| value.fmt(f))
+    }
+
+    pub(crate) fn debug(value: &impl uDebug) -> Buffer {
+        format(|f| value.fmt(f))
+    }
+}
+
 /// Generate a bounded integer type.
 ///
 /// It takes in single struct or enum, with the content being a bounded range expression, whose
@@ -210,10 +450,10 @@ pub mod examples;
 /// # Limitations
 ///
 /// - Both bounds of ranges must be closed and a simple const expression involving only literals and
-/// the following operators:
+///   the following operators:
 ///     - Negation (`-x`)
 ///     - Addition (`x+y`), subtraction (`x-y`), multiplication (`x*y`), division (`x/y`) and
-///     remainder (`x%y`).
+///       remainder (`x%y`).
 ///     - Bitwise not (`!x`), XOR (`x^y`), AND (`x&y`) and OR (`x|y`).
 #[cfg(feature = "macro")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "macro")))]
@@ -231,10 +471,20 @@ macro_rules! bounded_integer {
 block! {
     let alloc: ident = cfg_bool!(feature = "alloc");
     let arbitrary1: ident = cfg_bool!(feature = "arbitrary1");
+    let borsh1: ident = cfg_bool!(feature = "borsh1");
     let bytemuck1: ident = cfg_bool!(feature = "bytemuck1");
+    let clap4: ident = cfg_bool!(feature = "clap4");
+    let defmt1: ident = cfg_bool!(feature = "defmt1");
+    let pyo3028: ident = cfg_bool!(feature = "pyo3028");
+    let rkyv08: ident = cfg_bool!(feature = "rkyv08");
+    let rusqlite040: ident = cfg_bool!(feature = "rusqlite040");
+    let schemars1: ident = cfg_bool!(feature = "schemars1");
     let serde1: ident = cfg_bool!(feature = "serde1");
     let std: ident = cfg_bool!(feature = "std");
+    let ufmt02: ident = cfg_bool!(feature = "ufmt02");
+    let utoipa5: ident = cfg_bool!(feature = "utoipa5");
     let zerocopy06: ident = cfg_bool!(feature = "zerocopy06");
+    let zerocopy08: ident = cfg_bool!(feature = "zerocopy08");
     let step_trait: ident = cfg_bool!(feature = "step_trait");
     let d: tt = dollar!();
 
@@ -243,7 +493,7 @@ block! {
     macro_rules! __bounded_integer_inner2 {
         ($d($d tt:tt)*) => {
             $crate::__private::proc_macro! {
-                [$crate] $alloc $arbitrary1 $bytemuck1 $serde1 $std $zerocopy06 $step_trait $d($d tt)*
+                [$crate] $alloc $arbitrary1 $borsh1 $bytemuck1 $clap4 $defmt1 $pyo3028 $rkyv08 $rusqlite040 $schemars1 $serde1 $std $ufmt02 $utoipa5 $zerocopy06 $zerocopy08 $step_trait $d($d tt)*
             }
         };
     }
@@ -0,0 +1,263 @@
+//! Packing tuples of bounded integers into a single integer.
+
+use crate::BoundedBits;
+use core::marker::PhantomData;
+
+/// A tuple of bounded integers that can be packed into a single unsigned integer.
+///
+/// Each element is stored as a digit of a mixed-radix number whose radix is the number of values
+/// that element can hold, with the first element being the most significant. This means the
+/// packed integer takes up as little space as possible, even less than with a
+/// [`BitWriter`](crate::BitWriter), and that packed tuples compare in the same order as the tuples
+/// themselves.
+///
+/// This is implemented for tuples of up to 12 [`BoundedBits`] types. Packing into an integer that
+/// is too small to hold every possible tuple fails to compile.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// use bounded_integer::{BoundedI8, BoundedU8, BoundedU16, Pack};
+///
+/// type Weekday = BoundedU8<0, 6>;
+/// type Hour = BoundedU8<0, 23>;
+/// type Year = BoundedU16<1900, 2155>;
+/// assert_eq!(<(Weekday, Hour, Year)>::MAX_PACKED, 7 * 24 * 256 - 1);
+///
+/// let time = (Weekday::new(3).unwrap(), Hour::new(17).unwrap(), Year::new(2024).unwrap());
+/// let packed: u16 = time.pack();
+/// assert_eq!(packed, (3 * 24 + 17) * 256 + 124);
+/// assert_eq!(<(Weekday, Hour, Year)>::unpack(packed), Some(time));
+/// assert_eq!(<(Weekday, Hour, Year)>::unpack(u16::MAX), None);
+///
+/// // Signed integers are stored as their distance from the minimum value.
+/// let packed: u32 = (BoundedI8::<-5, 5>::new(-5).unwrap(), Hour::MAX).pack();
+/// assert_eq!(packed, 23);
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+///
+/// Packing into too small an integer does not compile:
+///
+/// ```compile_fail
+#[cfg_attr(not(feature = "types"), doc = "# compile_error!(\"\");")]
+/// # use bounded_integer::{BoundedU8, Pack};
+/// let packed: u8 = (BoundedU8::<0, 15>::MAX, BoundedU8::<0, 16>::MAX).pack();
+/// ```
+pub trait Pack: Sized {
+    /// The largest packed value, which is one less than the product of the number of values each
+    /// element can hold.
+    ///
+    /// Using this constant will fail to compile if the number does not fit in a `u128`.
+    const MAX_PACKED: u128;
+
+    /// Packs the tuple into an integer.
+    ///
+    /// Fails to compile if [`MAX_PACKED`](Self::MAX_PACKED) does not fit in `P`.
+    #[must_use]
+    fn pack<P: Packed>(self) -> P;
+
+    /// Unpacks a tuple from an integer, returning [`None`] if it is greater than
+    /// [`MAX_PACKED`](Self::MAX_PACKED).
+    ///
+    /// Fails to compile if [`MAX_PACKED`](Self::MAX_PACKED) does not fit in `P`.
+    #[must_use]
+    fn unpack<P: Packed>(packed: P) -> Option<Self>;
+}
+
+/// An unsigned integer that tuples of bounded integers can be [packed](Pack) into.
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64` and `u128`.
+pub trait Packed: Copy + private::Sealed {
+    /// The largest value of the integer.
+    const MAX: u128;
+}
+
+mod private {
+    pub trait Sealed {
+        fn from_u128(n: u128) -> Self;
+        fn to_u128(self) -> u128;
+    }
+}
+
+macro_rules! impl_packed {
+    ($($ty:ident)*) => { $(
+        impl Packed for $ty {
+            const MAX: u128 = $ty::MAX as u128;
+        }
+
+        impl private::Sealed for $ty {
+            #[inline]
+            fn from_u128(n: u128) -> Self {
+                n as $ty
+            }
+
+            #[inline]
+            fn to_u128(self) -> u128 {
+                self.into()
+            }
+        }
+    )* };
+}
+impl_packed!(u8 u16 u32 u64 u128);
+
+struct Fits<T, P>(PhantomData<(T, P)>);
+
+impl<T: Pack, P: Packed> Fits<T, P> {
+    const OK: () = assert!(
+        T::MAX_PACKED <= P::MAX,
+        "packed tuple of bounded integers does not fit in the integer",
+    );
+}
+
+/// Appends a digit with the given maximum to the maximum packed value.
+const fn push_max(max: u128, digit_max: u128) -> u128 {
+    // `max * (digit_max + 1) + digit_max`, without overflowing when `digit_max` is `u128::MAX`.
+    let shifted = match max.checked_mul(digit_max) {
+        Some(n) => n.checked_add(max),
+        None => None,
+    };
+    match shifted {
+        Some(n) => match n.checked_add(digit_max) {
+            Some(n) => n,
+            None => panic!("packed tuple of bounded integers does not fit in a `u128`"),
+        },
+        None => panic!("packed tuple of bounded integers does not fit in a `u128`"),
+    }
+}
+
+/// Appends a digit to a packed value that is known not to overflow.
+#[inline]
+fn push<T: BoundedBits>(packed: u128, value: T) -> u128 {
+    packed
+        .wrapping_mul(T::MAX_OFFSET)
+        .wrapping_add(packed)
+        .wrapping_add(value.to_offset_bits())
+}
+
+/// Removes the last digit from a packed value.
+#[inline]
+fn pop<T: BoundedBits>(packed: &mut u128) -> Option<T> {
+    let offset = match T::MAX_OFFSET.checked_add(1) {
+        Some(radix) => {
+            let offset = *packed % radix;
+            *packed /= radix;
+            offset
+        }
+        None => core::mem::take(packed),
+    };
+    T::from_offset_bits(offset)
+}
+
+macro_rules! impl_pack {
+    ($($t:ident)*) => {
+        impl<$($t: BoundedBits,)*> Pack for ($($t,)*) {
+            const MAX_PACKED: u128 = {
+                let max = 0;
+                $(let max = push_max(max, $t::MAX_OFFSET);)*
+                max
+            };
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn pack<P: Packed>(self) -> P {
+                let () = Fits::<Self, P>::OK;
+                let ($($t,)*) = self;
+                let packed = 0;
+                $(let packed = push(packed, $t);)*
+                P::from_u128(packed)
+            }
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn unpack<P: Packed>(packed: P) -> Option<Self> {
+                let () = Fits::<Self, P>::OK;
+                let mut packed = packed.to_u128();
+                impl_pack!(@pop packed [] $($t)*);
+                (packed == 0).then_some(($($t,)*))
+            }
+        }
+    };
+    // Reverse the list of types, since the last element is the least significant.
+    (@pop $packed:ident [$($rev:ident)*] $first:ident $($rest:ident)*) => {
+        impl_pack!(@pop $packed [$first $($rev)*] $($rest)*);
+    };
+    (@pop $packed:ident [$($rev:ident)*]) => {
+        $(let $rev = pop::<$rev>(&mut $packed)?;)*
+    };
+}
+impl_pack!(A);
+impl_pack!(A B);
+impl_pack!(A B C);
+impl_pack!(A B C D);
+impl_pack!(A B C D E);
+impl_pack!(A B C D E F);
+impl_pack!(A B C D E F G);
+impl_pack!(A B C D E F G H);
+impl_pack!(A B C D E F G H I);
+impl_pack!(A B C D E F G H I J);
+impl_pack!(A B C D E F G H I J K);
+impl_pack!(A B C D E F G H I J K L);
+
+#[cfg(test)]
+#[cfg(feature = "types")]
+mod tests {
+    use super::{Pack, Packed};
+    use crate::{BoundedU128, BoundedU64, BoundedU8};
+
+    fn round_trip<T: Pack + Copy + PartialEq + core::fmt::Debug, P: Packed>(value: T) -> P {
+        let packed = value.pack::<P>();
+        assert_eq!(T::unpack(packed), Some(value));
+        packed
+    }
+
+    #[test]
+    fn mixed_radix() {
+        type T = (BoundedU8<0, 2>, BoundedU8<5, 5>, BoundedU8<0, 9>);
+        let value = |a, c| {
+            (
+                BoundedU8::new(a).unwrap(),
+                BoundedU8::MIN,
+                BoundedU8::new(c).unwrap(),
+            )
+        };
+        assert_eq!(T::MAX_PACKED, 29);
+        assert_eq!(round_trip::<T, u8>(value(0, 0)), 0);
+        assert_eq!(round_trip::<T, u8>(value(1, 7)), 17);
+        assert_eq!(round_trip::<T, u128>(value(2, 9)), 29);
+        assert_eq!(T::unpack(30_u8), None);
+        assert_eq!(T::unpack(u8::MAX), None);
+    }
+
+    #[test]
+    fn full_width() {
+        type Unit = BoundedU8<0, 0>;
+        type Full = BoundedU128<0, { u128::MAX }>;
+        assert_eq!(<(Full,)>::MAX_PACKED, u128::MAX);
+        assert_eq!(<(Unit, Full, Unit)>::MAX_PACKED, u128::MAX);
+        assert_eq!(round_trip::<_, u128>((Unit::MIN, Full::MAX)), u128::MAX);
+        assert_eq!(
+            round_trip::<_, u128>((
+                BoundedU8::<0, 1>::MAX,
+                BoundedU64::<0, { u64::MAX }>::new(5).unwrap(),
+            )),
+            1 << 64 | 5
+        );
+    }
+
+    #[test]
+    fn order() {
+        type T = (BoundedU8<0, 3>, BoundedU8<0, 4>);
+        let mut previous = None;
+        for a in 0..=3 {
+            for b in 0..=4 {
+                let value = (BoundedU8::new(a).unwrap(), BoundedU8::new(b).unwrap());
+                let packed = round_trip::<T, u8>(value);
+                assert!(previous < Some(packed));
+                previous = Some(packed);
+            }
+        }
+        assert_eq!(previous, Some(19));
+    }
+}
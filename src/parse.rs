@@ -72,8 +72,10 @@ from_str_radix_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
 /// An error which can be returned when parsing a bounded integer.
 ///
 /// This is the error type of all bounded integers' `from_str_radix()` functions (such as
-/// [`BoundedI8::from_str_radix`](crate::BoundedI8::from_str_radix)) as well as their
-/// [`FromStr`](std::str::FromStr) implementations.
+/// [`BoundedI8::from_str_radix`](crate::BoundedI8::from_str_radix)) and their
+/// [`FromStr`](std::str::FromStr) implementations, as well as of their conversions from bytes
+/// (such as [`BoundedI8::try_from_le_bytes`](crate::BoundedI8::try_from_le_bytes)), which only
+/// fail with [`AboveMax`](ParseErrorKind::AboveMax) or [`BelowMin`](ParseErrorKind::BelowMin).
 #[derive(Debug, Clone)]
 pub struct ParseError {
     kind: ParseErrorKind,
@@ -122,12 +124,12 @@ pub enum ParseErrorKind {
     BelowMin,
 }
 
-pub fn error_below_min() -> ParseError {
+pub const fn error_below_min() -> ParseError {
     ParseError {
         kind: ParseErrorKind::BelowMin,
     }
 }
-pub fn error_above_max() -> ParseError {
+pub const fn error_above_max() -> ParseError {
     ParseError {
         kind: ParseErrorKind::AboveMax,
     }
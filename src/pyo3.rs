@@ -0,0 +1,31 @@
+//! Helpers shared by the implementations of PyO3's traits.
+
+use alloc::format;
+use core::fmt::Display;
+use pyo3028::exceptions::{PyOverflowError, PyValueError};
+use pyo3028::types::PyAny;
+use pyo3028::{Borrowed, FromPyObject, PyErr, PyResult};
+
+/// Extracts a bounded integer from a Python object.
+///
+/// Integers that don't fit in the inner integer raise `OverflowError`, and integers that do but are
+/// out of range raise `ValueError`, both with the range in the message.
+pub fn extract<'py, Inner, T>(
+    obj: Borrowed<'_, 'py, PyAny>,
+    new: fn(Inner) -> Option<T>,
+    min: impl Display,
+    max: impl Display,
+) -> PyResult<T>
+where
+    Inner: for<'a> FromPyObject<'a, 'py, Error = PyErr> + Display + Copy,
+{
+    let n = match Inner::extract(obj) {
+        Ok(n) => n,
+        Err(e) if e.is_instance_of::<PyOverflowError>(obj.py()) => {
+            let message = format!("{} is not in {min}..={max}", obj.as_any());
+            return Err(PyOverflowError::new_err(message));
+        }
+        Err(e) => return Err(e),
+    };
+    new(n).ok_or_else(|| PyValueError::new_err(format!("{n} is not in {min}..={max}")))
+}
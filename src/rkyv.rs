@@ -0,0 +1,24 @@
+//! Helpers shared by the implementations of rkyv's traits.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned when a bounded integer can't be archived, or an archived one is invalid.
+#[derive(Debug)]
+pub enum ArchiveError {
+    /// An archived bounded integer was out of range.
+    OutOfRange,
+    /// A pointer-sized bounded integer did not fit in its archived form.
+    TooLarge,
+}
+
+impl Display for ArchiveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::OutOfRange => "archived bounded integer is out of range",
+            Self::TooLarge => "bounded integer is too large for its archived form",
+        })
+    }
+}
+
+impl Error for ArchiveError {}
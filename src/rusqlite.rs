@@ -0,0 +1,30 @@
+//! Helpers shared by the implementations of rusqlite's traits.
+
+use alloc::boxed::Box;
+use core::error::Error;
+use rusqlite040::types::{FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+
+/// Converts a bounded integer's value to an SQLite integer.
+pub fn to_sql<Inner>(n: Inner) -> rusqlite040::Result<ToSqlOutput<'static>>
+where
+    i64: TryFrom<Inner>,
+    <i64 as TryFrom<Inner>>::Error: Error + Send + Sync + 'static,
+{
+    match i64::try_from(n) {
+        Ok(n) => Ok(ToSqlOutput::from(n)),
+        Err(e) => Err(rusqlite040::Error::ToSqlConversionFailure(Box::new(e))),
+    }
+}
+
+/// Converts an SQLite integer to a bounded integer, failing with [`FromSqlError::OutOfRange`] if
+/// it is out of range.
+pub fn from_sql<Inner: TryFrom<i64>, T>(
+    value: ValueRef<'_>,
+    new: fn(Inner) -> Option<T>,
+) -> FromSqlResult<T> {
+    let n = value.as_i64()?;
+    Inner::try_from(n)
+        .ok()
+        .and_then(new)
+        .ok_or(FromSqlError::OutOfRange(n))
+}
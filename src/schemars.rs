@@ -0,0 +1,52 @@
+//! Helpers shared by the implementations of schemars's traits.
+
+use alloc::borrow::Cow;
+use alloc::format;
+#[cfg(feature = "macro")]
+use alloc::vec::Vec;
+use core::fmt::Display;
+use schemars1::{JsonSchema, Schema, SchemaGenerator};
+
+/// The name of a bounded integer's schema, which includes its range, e.g. `BoundedU8_1_7`.
+pub fn name(name: &str, min: impl Display, max: impl Display) -> Cow<'static, str> {
+    Cow::Owned(format!("{name}_{min}_{max}"))
+}
+
+/// The schema of a bounded integer, which is that of its inner integer with the bounds narrowed.
+///
+/// Bounds that don't fit in a JSON integer are left as they are for the inner integer.
+pub fn schema<Inner>(generator: &mut SchemaGenerator, min: Inner, max: Inner) -> Schema
+where
+    Inner: JsonSchema + Copy,
+    i64: TryFrom<Inner>,
+    u64: TryFrom<Inner>,
+{
+    let mut schema = Inner::json_schema(generator);
+    for (key, value) in [("minimum", min), ("maximum", max)] {
+        if let Ok(value) = u64::try_from(value) {
+            schema.insert(key.into(), value.into());
+        } else if let Ok(value) = i64::try_from(value) {
+            schema.insert(key.into(), value.into());
+        }
+    }
+    schema
+}
+
+/// Lists the values of a bounded enum in its schema, if they all fit in JSON integers.
+#[cfg(feature = "macro")]
+pub fn list_values<Inner>(schema: &mut Schema, values: &[Inner])
+where
+    Inner: Copy,
+    i64: TryFrom<Inner>,
+    u64: TryFrom<Inner>,
+{
+    let unsigned = values.iter().map(|&v| u64::try_from(v).ok());
+    if let Some(values) = unsigned.collect::<Option<Vec<_>>>() {
+        schema.insert("enum".into(), values.into());
+        return;
+    }
+    let signed = values.iter().map(|&v| i64::try_from(v).ok());
+    if let Some(values) = signed.collect::<Option<Vec<_>>>() {
+        schema.insert("enum".into(), values.into());
+    }
+}
@@ -0,0 +1,530 @@
+//! Alternative [`serde`](serde1) representations of bounded integers, for use with
+//! `#[serde(with = "...")]`.
+//!
+//! These work with any bounded integer in this crate, both macro-generated and const-generic.
+//!
+//! - [`offset`] serializes the distance from the minimum value, in as few bytes as possible.
+//! - [`saturating`] and [`wrapping`] accept integers of any width when deserializing, clamping or
+//!   wrapping them into range instead of failing.
+
+use crate::BoundedBits;
+use core::fmt::{self, Formatter};
+use serde1::de::{self, Deserializer, Error as _, Visitor};
+
+pub mod offset {
+    //! Serializes a bounded integer as its distance from the minimum value, using the smallest
+    //! unsigned integer type that can hold every value in its range.
+    //!
+    //! This is much more compact in formats such as postcard and bincode for integers whose
+    //! range is small but far from zero. Deserializing fails if the offset is out of range.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    #![cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+    #![cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+    //! # extern crate serde1 as serde;
+    //! use bounded_integer::BoundedU32;
+    //! use serde::{Deserialize, Serialize};
+    //!
+    //! #[derive(Serialize, Deserialize)]
+    //! # #[serde(crate = "serde")]
+    //! struct Reading {
+    //!     // Serialized as a `u8`.
+    //!     #[serde(with = "bounded_integer::serde::offset")]
+    //!     sensor: BoundedU32<4_000_000_000, 4_000_000_100>,
+    //! }
+    #![cfg_attr(not(feature = "types"), doc = "# }")]
+    //! ```
+
+    use crate::BoundedBits;
+    use serde1::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the distance of the value from the minimum value.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serializer fails.
+    pub fn serialize<T: BoundedBits, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let offset = value.to_offset_bits();
+        match T::BITS {
+            0..=8 => serializer.serialize_u8(offset as u8),
+            9..=16 => serializer.serialize_u16(offset as u16),
+            17..=32 => serializer.serialize_u32(offset as u32),
+            33..=64 => serializer.serialize_u64(offset as u64),
+            _ => serializer.serialize_u128(offset),
+        }
+    }
+
+    /// Deserializes a value from its distance from the minimum value.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the deserializer fails or the offset is out of range.
+    pub fn deserialize<'de, T: BoundedBits, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let offset = match T::BITS {
+            0..=8 => u8::deserialize(deserializer)?.into(),
+            9..=16 => u16::deserialize(deserializer)?.into(),
+            17..=32 => u32::deserialize(deserializer)?.into(),
+            33..=64 => u64::deserialize(deserializer)?.into(),
+            _ => u128::deserialize(deserializer)?,
+        };
+        T::from_offset_bits(offset).ok_or_else(|| {
+            D::Error::custom(format_args!(
+                "offset out of range, expected it to be at most {}",
+                T::MAX_OFFSET,
+            ))
+        })
+    }
+}
+
+macro_rules! lenient_modules {
+    ($($(#[$attr:meta])* $name:ident($to_offset:ident),)*) => { $(
+        $(#[$attr])*
+        pub mod $name {
+            use super::Lenient;
+            use serde1::{Deserialize, Deserializer, Serialize, Serializer};
+
+            /// Serializes the value as usual.
+            ///
+            /// # Errors
+            ///
+            /// Fails if the serializer fails.
+            pub fn serialize<T: Serialize, S: Serializer>(
+                value: &T,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                value.serialize(serializer)
+            }
+
+            /// Deserializes an integer of any width, bringing it into range.
+            ///
+            /// # Errors
+            ///
+            /// Fails if the deserializer fails or does not contain an integer.
+            pub fn deserialize<'de, T: Lenient, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<T, D::Error> {
+                super::deserialize_lenient(deserializer, super::$to_offset::<T>)
+            }
+
+            struct Seed<T>(T);
+
+            impl<'de, T: Lenient> Deserialize<'de> for Seed<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize(deserializer).map(Self)
+                }
+            }
+
+            /// The same, for optional bounded integers.
+            pub mod option {
+                use super::{Lenient, Seed};
+                use serde1::{Deserialize, Deserializer, Serialize, Serializer};
+
+                /// Serializes the value as usual.
+                ///
+                /// # Errors
+                ///
+                /// Fails if the serializer fails.
+                pub fn serialize<T: Serialize, S: Serializer>(
+                    value: &Option<T>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    value.serialize(serializer)
+                }
+
+                /// Deserializes an optional integer of any width, bringing it into range.
+                ///
+                /// # Errors
+                ///
+                /// Fails if the deserializer fails or does not contain an optional integer.
+                pub fn deserialize<'de, T: Lenient, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Option<T>, D::Error> {
+                    let value = Option::<Seed<T>>::deserialize(deserializer)?;
+                    Ok(value.map(|value| value.0))
+                }
+            }
+
+            /// The same, for `Vec`s of bounded integers.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+            pub mod vec {
+                use super::{Lenient, Seed};
+                use alloc::vec::Vec;
+                use serde1::{Deserialize, Deserializer, Serialize, Serializer};
+
+                /// Serializes the values as usual.
+                ///
+                /// # Errors
+                ///
+                /// Fails if the serializer fails.
+                pub fn serialize<T: Serialize, S: Serializer>(
+                    values: &[T],
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    values.serialize(serializer)
+                }
+
+                /// Deserializes a sequence of integers of any width, bringing each into range.
+                ///
+                /// # Errors
+                ///
+                /// Fails if the deserializer fails or does not contain a sequence of integers.
+                pub fn deserialize<'de, T: Lenient, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Vec<T>, D::Error> {
+                    let values = Vec::<Seed<T>>::deserialize(deserializer)?;
+                    Ok(values.into_iter().map(|value| value.0).collect())
+                }
+            }
+        }
+    )* };
+}
+
+lenient_modules! {
+    /// Deserializes bounded integers from integers of any width, clamping out-of-range values to
+    /// the nearest bound like [`new_saturating`](crate::BoundedU8::new_saturating) does.
+    ///
+    /// Serialization is unchanged. Submodules support optional and `Vec`s of bounded integers.
+    ///
+    /// Self-describing formats such as JSON may contain an integer of any width, while other formats
+    /// must contain the integer type the bounded integer is serialized as.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+    #[cfg_attr(not(all(feature = "types", feature = "alloc")), doc = "# #[cfg(any())] {")]
+    /// # extern crate serde1 as serde;
+    /// # extern crate serde_json1 as serde_json;
+    /// use bounded_integer::BoundedU8;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// # #[serde(crate = "serde")]
+    /// struct Reading {
+    ///     #[serde(with = "bounded_integer::serde::saturating")]
+    ///     humidity: BoundedU8<0, 100>,
+    ///     #[serde(with = "bounded_integer::serde::saturating::vec")]
+    ///     history: Vec<BoundedU8<0, 100>>,
+    /// }
+    ///
+    /// let reading: Reading = serde_json::from_str(r#"{
+    ///     "humidity": 104,
+    ///     "history": [-3, 50, 1000000]
+    /// }"#).unwrap();
+    /// assert_eq!(reading.humidity, 100);
+    /// assert_eq!(reading.history, [0, 50, 100]);
+    #[cfg_attr(not(all(feature = "types", feature = "alloc")), doc = "# }")]
+    /// ```
+    saturating(saturating_offset),
+
+    /// Deserializes bounded integers from integers of any width, wrapping out-of-range values
+    /// around the range so that one above the maximum becomes the minimum.
+    ///
+    /// Serialization is unchanged. Submodules support optional and `Vec`s of bounded integers, and
+    /// the same formats are supported as with [`saturating`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+    #[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+    /// # extern crate serde1 as serde;
+    /// # extern crate serde_json1 as serde_json;
+    /// use bounded_integer::BoundedI16;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// # #[serde(crate = "serde")]
+    /// struct Heading {
+    ///     #[serde(with = "bounded_integer::serde::wrapping::option")]
+    ///     degrees: Option<BoundedI16<-180, 179>>,
+    /// }
+    ///
+    /// let heading: Heading = serde_json::from_str(r#"{ "degrees": 270 }"#).unwrap();
+    /// assert_eq!(heading.degrees.unwrap(), -90);
+    /// let heading: Heading = serde_json::from_str(r#"{ "degrees": null }"#).unwrap();
+    /// assert_eq!(heading.degrees, None);
+    #[cfg_attr(not(feature = "types"), doc = "# }")]
+    /// ```
+    wrapping(wrapping_offset),
+}
+
+/// Bounded integers that can be deserialized with [`saturating`] and [`wrapping`].
+///
+/// This is an implementation detail of the crate and is implemented for all bounded integers.
+#[doc(hidden)]
+pub trait Lenient: BoundedBits {
+    /// Whether the underlying integer type is signed.
+    const SIGNED: bool;
+
+    /// The number of bits in the underlying integer type.
+    const INNER_BITS: u32;
+
+    /// The minimum value, sign-extended to a `u128`.
+    const MIN_BITS: u128;
+}
+
+/// An integer of any width, as a sign and a two's complement `u128`.
+#[derive(Clone, Copy)]
+struct Wide {
+    non_negative: bool,
+    bits: u128,
+}
+
+impl Wide {
+    fn min<T: Lenient>() -> Self {
+        Self {
+            non_negative: !T::SIGNED || (T::MIN_BITS as i128) >= 0,
+            bits: T::MIN_BITS,
+        }
+    }
+
+    /// Computes `self - rhs` as a multiple of 2<sup>128</sup> in the range -2..=1 and a
+    /// remainder.
+    fn sub(self, rhs: Self) -> (i8, u128) {
+        let (bits, borrow) = self.bits.overflowing_sub(rhs.bits);
+        let high = i8::from(!rhs.non_negative) - i8::from(!self.non_negative) - i8::from(borrow);
+        (high, bits)
+    }
+}
+
+struct WideVisitor;
+
+impl Visitor<'_> for WideVisitor {
+    type Value = Wide;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("an integer")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_i128(v.into())
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Wide {
+            non_negative: v >= 0,
+            bits: v as u128,
+        })
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_u128(v.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Wide {
+            non_negative: true,
+            bits: v,
+        })
+    }
+}
+
+fn deserialize_lenient<'de, T: Lenient, D: Deserializer<'de>>(
+    deserializer: D,
+    to_offset: fn(Wide) -> u128,
+) -> Result<T, D::Error> {
+    // Non-self-describing formats need to be told the type that was serialized.
+    let n = match (T::SIGNED, T::INNER_BITS) {
+        (false, 8) => deserializer.deserialize_u8(WideVisitor),
+        (false, 16) => deserializer.deserialize_u16(WideVisitor),
+        (false, 32) => deserializer.deserialize_u32(WideVisitor),
+        (false, 64) => deserializer.deserialize_u64(WideVisitor),
+        (false, _) => deserializer.deserialize_u128(WideVisitor),
+        (true, 8) => deserializer.deserialize_i8(WideVisitor),
+        (true, 16) => deserializer.deserialize_i16(WideVisitor),
+        (true, 32) => deserializer.deserialize_i32(WideVisitor),
+        (true, 64) => deserializer.deserialize_i64(WideVisitor),
+        (true, _) => deserializer.deserialize_i128(WideVisitor),
+    }?;
+    T::from_offset_bits(to_offset(n))
+        .ok_or_else(|| D::Error::custom("bounded integer has an empty range"))
+}
+
+fn saturating_offset<T: Lenient>(n: Wide) -> u128 {
+    match n.sub(Wide::min::<T>()) {
+        (0, offset) => offset.min(T::MAX_OFFSET),
+        (high, _) if high < 0 => 0,
+        _ => T::MAX_OFFSET,
+    }
+}
+
+fn wrapping_offset<T: Lenient>(n: Wide) -> u128 {
+    let (high, low) = n.sub(Wide::min::<T>());
+    let Some(count) = T::MAX_OFFSET.checked_add(1) else {
+        return low;
+    };
+    // 2^128 modulo the number of values.
+    let carry = (u128::MAX % count + 1) % count;
+    let add = |a: u128, b: u128| {
+        if a >= count - b {
+            a - (count - b)
+        } else {
+            a + b
+        }
+    };
+    let sub = |a: u128, b: u128| if a >= b { a - b } else { a + (count - b) };
+
+    let offset = low % count;
+    match high {
+        1 => add(offset, carry),
+        0 => offset,
+        -1 => sub(offset, carry),
+        _ => sub(sub(offset, carry), carry),
+    }
+}
+
+#[cfg(all(test, feature = "types", feature = "alloc"))]
+mod tests {
+    use crate::{
+        BoundedI128, BoundedI64, BoundedI8, BoundedU128, BoundedU32, BoundedU64, BoundedU8,
+    };
+    use alloc::{vec, vec::Vec};
+    use serde1::de::value::{Error, I128Deserializer, U128Deserializer};
+    use serde1::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "serde1")]
+    struct Offsets {
+        #[serde(with = "super::offset")]
+        small: BoundedU32<4_000_000_000, 4_000_000_100>,
+        #[serde(with = "super::offset")]
+        signed: BoundedI64<-1000, 1000>,
+        #[serde(with = "super::offset")]
+        unit: BoundedU8<7, 7>,
+        #[serde(with = "super::offset")]
+        full: BoundedI64<{ i64::MIN }, { i64::MAX }>,
+    }
+
+    fn offsets() -> Offsets {
+        Offsets {
+            small: BoundedU32::new(4_000_000_090).unwrap(),
+            signed: BoundedI64::new(-1).unwrap(),
+            unit: BoundedU8::MIN,
+            full: BoundedI64::MIN,
+        }
+    }
+
+    #[test]
+    fn offset_postcard() {
+        let mut buf = [0; 32];
+        let bytes = postcard1::to_slice(&offsets(), &mut buf).unwrap();
+        assert_eq!(bytes, [90, 0xE7, 0x07, 0, 0]);
+        assert_eq!(postcard1::from_bytes::<Offsets>(bytes).unwrap(), offsets());
+
+        assert!(postcard1::from_bytes::<Offsets>(&[101, 0, 0, 0]).is_err());
+        assert!(postcard1::from_bytes::<Offsets>(&[0, 0xD1, 0x0F, 0, 0]).is_err());
+        assert!(postcard1::from_bytes::<Offsets>(&[0, 0, 1, 0]).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "serde1")]
+    struct Lenient {
+        #[serde(with = "super::saturating")]
+        saturating: BoundedI8<-10, 10>,
+        #[serde(with = "super::wrapping")]
+        wrapping: BoundedI8<-10, 10>,
+        #[serde(with = "super::saturating::option")]
+        option: Option<BoundedU32<5, 7>>,
+        #[serde(with = "super::wrapping::vec")]
+        vec: Vec<BoundedU64<{ u64::MAX - 2 }, { u64::MAX }>>,
+    }
+
+    #[test]
+    fn lenient_json() {
+        let json = r#"{ "saturating": 300, "wrapping": 11, "option": 2, "vec": [-1, 0, 1] }"#;
+        let lenient: Lenient = serde_json1::from_str(json).unwrap();
+        assert_eq!(lenient.saturating, 10);
+        assert_eq!(lenient.wrapping, -10);
+        assert_eq!(lenient.option, Some(BoundedU32::new(5).unwrap()));
+        assert_eq!(lenient.vec, [u64::MAX - 1, u64::MAX, u64::MAX - 2]);
+
+        let json = r#"{ "saturating": -11, "wrapping": -32, "option": null, "vec": [] }"#;
+        let lenient: Lenient = serde_json1::from_str(json).unwrap();
+        assert_eq!(lenient.saturating, -10);
+        assert_eq!(lenient.wrapping, 10);
+        assert_eq!(lenient.option, None);
+
+        let roundtrip = serde_json1::to_string(&lenient).unwrap();
+        assert_eq!(
+            roundtrip,
+            r#"{"saturating":-10,"wrapping":10,"option":null,"vec":[]}"#
+        );
+
+        let json = r#"{ "saturating": "1", "wrapping": 0, "option": null, "vec": [] }"#;
+        assert!(serde_json1::from_str::<Lenient>(json).is_err());
+    }
+
+    #[test]
+    fn lenient_postcard() {
+        let lenient = Lenient {
+            saturating: BoundedI8::new(3).unwrap(),
+            wrapping: BoundedI8::MIN,
+            option: Some(BoundedU32::MAX),
+            vec: vec![BoundedU64::MIN],
+        };
+        let mut buf = [0; 64];
+        let bytes = postcard1::to_slice(&lenient, &mut buf).unwrap();
+        assert_eq!(postcard1::from_bytes::<Lenient>(bytes).unwrap(), lenient);
+    }
+
+    fn saturating<T: super::Lenient>(n: i128) -> T {
+        super::saturating::deserialize(I128Deserializer::<Error>::new(n)).unwrap()
+    }
+
+    fn wrapping<T: super::Lenient>(n: i128) -> T {
+        super::wrapping::deserialize(I128Deserializer::<Error>::new(n)).unwrap()
+    }
+
+    #[test]
+    fn extremes() {
+        type U128 = BoundedU128<{ u128::MAX - 9 }, { u128::MAX }>;
+        type I128 = BoundedI128<{ i128::MIN }, { i128::MIN + 9 }>;
+        type FullI128 = BoundedI128<{ i128::MIN }, { i128::MAX }>;
+
+        assert_eq!(saturating::<U128>(i128::MIN), U128::MIN);
+        assert_eq!(saturating::<I128>(i128::MAX), I128::MAX);
+        assert_eq!(saturating::<BoundedU8<0, 255>>(-1), 0);
+        assert_eq!(saturating::<BoundedU8<0, 255>>(256), 255);
+
+        assert_eq!(wrapping::<U128>(i128::MAX), u128::MAX - 8);
+        assert_eq!(wrapping::<U128>(-1), u128::MAX - 6);
+        assert_eq!(wrapping::<U128>(i128::MIN), u128::MAX - 3);
+        assert_eq!(wrapping::<I128>(i128::MAX), i128::MIN + 5);
+        assert_eq!(wrapping::<I128>(0), i128::MIN + 8);
+        assert_eq!(wrapping::<FullI128>(-5), -5);
+        assert_eq!(wrapping::<BoundedU8<0, 255>>(-1), 255);
+        assert_eq!(wrapping::<BoundedI8<-128, 127>>(200), -56);
+        assert_eq!(wrapping::<BoundedI8<1, 3>>(-1), 2);
+
+        let value = U128Deserializer::<Error>::new(u128::MAX);
+        let full: BoundedU128<0, { u128::MAX }> = super::wrapping::deserialize(value).unwrap();
+        assert_eq!(full, u128::MAX);
+    }
+
+    #[test]
+    fn offset_bincode() {
+        let bytes = bincode1::serialize(&offsets()).unwrap();
+        assert_eq!(bytes, [90, 0xE7, 0x03, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bincode1::deserialize::<Offsets>(&bytes).unwrap(), offsets());
+
+        let mut full = offsets();
+        full.full = BoundedI64::MAX;
+        let bytes = bincode1::serialize(&full).unwrap();
+        assert_eq!(bytes[4..], [0xFF; 8]);
+        assert_eq!(bincode1::deserialize::<Offsets>(&bytes).unwrap(), full);
+
+        assert!(bincode1::deserialize::<Offsets>(&[101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(
+            bincode1::deserialize::<Offsets>(&[0, 0xD1, 0x07, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_err()
+        );
+    }
+}
@@ -0,0 +1,549 @@
+//! A compact set of bounded integers.
+
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+use crate::BoundedKey;
+
+/// The number of keys stored in each word of a [`BoundedSet`].
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of values of the bounded integer `K`, stored inline as a bitmap with one bit for every
+/// possible value.
+///
+/// Due to the limits of const generics, the number of 64-bit words in the bitmap `N` must be given
+/// explicitly. It must be equal to [`K::COUNT`](BoundedKey::COUNT) divided by 64 and rounded up;
+/// for a concrete key type this can be written as `{ K::COUNT.div_ceil(64) }`. Constructing a
+/// `BoundedSet` with any other number of words fails to compile:
+///
+/// ```compile_fail
+#[cfg_attr(not(feature = "types"), doc = "# compile_error!(\"\");")]
+/// # use bounded_integer::{BoundedSet, BoundedU8};
+/// let set = BoundedSet::<BoundedU8<0, 64>, 1>::new();
+/// ```
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// # use bounded_integer::{BoundedSet, BoundedU8};
+/// type Channel = BoundedU8<0, 63>;
+///
+/// let mut active = BoundedSet::<Channel, 1>::new();
+/// active.insert(Channel::new(3).unwrap());
+/// active.insert(Channel::new(42).unwrap());
+///
+/// assert!(active.contains(Channel::new(42).unwrap()));
+/// assert!(!active.contains(Channel::new(7).unwrap()));
+/// assert_eq!(active.len(), 2);
+///
+/// let muted = [5, 42].into_iter().map(|n| Channel::new(n).unwrap()).collect();
+/// let audible = active - muted;
+/// assert!(audible.iter().eq([Channel::new(3).unwrap()]));
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+pub struct BoundedSet<K, const N: usize> {
+    words: [u64; N],
+    key: PhantomData<K>,
+}
+
+impl<K: BoundedKey, const N: usize> BoundedSet<K, N> {
+    const CHECK_LEN: () = assert!(
+        N == K::COUNT.div_ceil(WORD_BITS),
+        "number of words must be equal to `K::COUNT.div_ceil(64)`",
+    );
+
+    /// Creates an empty set.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        let () = Self::CHECK_LEN;
+        Self {
+            words: [0; N],
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a set containing every value of `K`.
+    #[must_use]
+    #[inline]
+    pub fn full() -> Self {
+        !Self::new()
+    }
+
+    /// Returns the word containing the bit of the given key, and the mask of that bit.
+    #[inline]
+    fn locate(key: K) -> (usize, u64) {
+        let index = key.to_index();
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    /// Returns whether the set contains the given key.
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, key: K) -> bool {
+        let (word, mask) = Self::locate(key);
+        // SAFETY: `to_index` is always less than `K::COUNT`, so `word` is less than `N`.
+        unsafe { self.words.get_unchecked(word) & mask != 0 }
+    }
+
+    /// Adds a key to the set, returning whether it was newly inserted.
+    #[inline]
+    pub fn insert(&mut self, key: K) -> bool {
+        let (word, mask) = Self::locate(key);
+        // SAFETY: `to_index` is always less than `K::COUNT`, so `word` is less than `N`.
+        let word = unsafe { self.words.get_unchecked_mut(word) };
+        let inserted = *word & mask == 0;
+        *word |= mask;
+        inserted
+    }
+
+    /// Removes a key from the set, returning whether it was present.
+    #[inline]
+    pub fn remove(&mut self, key: K) -> bool {
+        let (word, mask) = Self::locate(key);
+        // SAFETY: `to_index` is always less than `K::COUNT`, so `word` is less than `N`.
+        let word = unsafe { self.words.get_unchecked_mut(word) };
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    /// Removes all keys from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words = [0; N];
+    }
+
+    /// Returns the number of keys in the set.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns whether the set contains no keys.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the smallest key in the set.
+    #[must_use]
+    #[inline]
+    pub fn first(&self) -> Option<K> {
+        self.iter().next()
+    }
+
+    /// Returns the largest key in the set.
+    #[must_use]
+    #[inline]
+    pub fn last(&self) -> Option<K> {
+        self.iter().next_back()
+    }
+
+    /// Returns an iterator over the keys in the set in increasing order.
+    #[inline]
+    pub fn iter(&self) -> Iter<K, N> {
+        Iter {
+            words: self.words,
+            front: 0,
+            back: N,
+            len: self.len(),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the set of keys in either `self` or `other`.
+    #[must_use]
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        *self | *other
+    }
+
+    /// Returns the set of keys in both `self` and `other`.
+    #[must_use]
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        *self & *other
+    }
+
+    /// Returns the set of keys in `self` but not in `other`.
+    #[must_use]
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        *self - *other
+    }
+
+    /// Returns the set of keys in exactly one of `self` and `other`.
+    #[must_use]
+    #[inline]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        *self ^ *other
+    }
+
+    /// Returns whether every key in `self` is also in `other`.
+    #[must_use]
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// Returns whether every key in `other` is also in `self`.
+    #[must_use]
+    #[inline]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns whether `self` and `other` have no keys in common.
+    #[must_use]
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// Returns the underlying bitmap, in which the key with index `i` is stored in the bit `i % 64`
+    /// of the word `i / 64`.
+    #[must_use]
+    #[inline]
+    pub const fn as_words(&self) -> &[u64; N] {
+        &self.words
+    }
+}
+
+impl<K: BoundedKey, const N: usize> Default for BoundedSet<K, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, const N: usize> Clone for BoundedSet<K, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, const N: usize> Copy for BoundedSet<K, N> {}
+
+impl<K, const N: usize> PartialEq for BoundedSet<K, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl<K, const N: usize> Eq for BoundedSet<K, N> {}
+
+impl<K, const N: usize> core::hash::Hash for BoundedSet<K, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.words.hash(state);
+    }
+}
+
+impl<K: BoundedKey + Debug, const N: usize> Debug for BoundedSet<K, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self).finish()
+    }
+}
+
+macro_rules! set_ops {
+    ($($op:ident $method:ident $op_assign:ident $method_assign:ident, |$a:ident, $b:ident| $e:expr,)*) => { $(
+        impl<K: BoundedKey, const N: usize> $op for BoundedSet<K, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, rhs: Self) -> Self::Output {
+                self.$method_assign(rhs);
+                self
+            }
+        }
+
+        impl<K: BoundedKey, const N: usize> $op_assign for BoundedSet<K, N> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                for ($a, $b) in self.words.iter_mut().zip(rhs.words) {
+                    *$a = $e;
+                }
+            }
+        }
+    )* };
+}
+
+set_ops! {
+    BitOr bitor BitOrAssign bitor_assign, |a, b| *a | b,
+    BitAnd bitand BitAndAssign bitand_assign, |a, b| *a & b,
+    BitXor bitxor BitXorAssign bitxor_assign, |a, b| *a ^ b,
+    Sub sub SubAssign sub_assign, |a, b| *a & !b,
+}
+
+impl<K: BoundedKey, const N: usize> Not for BoundedSet<K, N> {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self::Output {
+        for word in &mut self.words {
+            *word = !*word;
+        }
+        // Keep the bits past the last key clear.
+        let unused = N * WORD_BITS - K::COUNT;
+        if let Some(last) = self.words.last_mut() {
+            *last &= u64::MAX >> unused;
+        }
+        self
+    }
+}
+
+impl<K: BoundedKey, const N: usize> FromIterator<K> for BoundedSet<K, N> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: BoundedKey, const N: usize> Extend<K> for BoundedSet<K, N> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<'a, K: BoundedKey, const N: usize> Extend<&'a K> for BoundedSet<K, N> {
+    fn extend<I: IntoIterator<Item = &'a K>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<K: BoundedKey, const N: usize> IntoIterator for &BoundedSet<K, N> {
+    type Item = K;
+    type IntoIter = Iter<K, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: BoundedKey, const N: usize> IntoIterator for BoundedSet<K, N> {
+    type Item = K;
+    type IntoIter = Iter<K, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the keys in a [`BoundedSet`] in increasing order.
+///
+/// This is created by [`BoundedSet::iter`] and the [`IntoIterator`] implementations of
+/// [`BoundedSet`].
+#[derive(Clone)]
+pub struct Iter<K, const N: usize> {
+    words: [u64; N],
+    /// The first word that may still contain keys.
+    front: usize,
+    /// One past the last word that may still contain keys.
+    back: usize,
+    len: usize,
+    key: PhantomData<K>,
+}
+
+impl<K: BoundedKey, const N: usize> Iterator for Iter<K, N> {
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let word = &mut self.words[self.front];
+            if *word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+                self.len -= 1;
+                // SAFETY: Only the bits of keys less than `K::COUNT` are ever set.
+                return Some(unsafe { K::from_index_unchecked(self.front * WORD_BITS + bit) });
+            }
+            self.front += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K: BoundedKey, const N: usize> DoubleEndedIterator for Iter<K, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let word = &mut self.words[self.back - 1];
+            if *word != 0 {
+                let bit = WORD_BITS - 1 - word.leading_zeros() as usize;
+                *word &= !(1 << bit);
+                self.len -= 1;
+                // SAFETY: Only the bits of keys less than `K::COUNT` are ever set.
+                return Some(unsafe { K::from_index_unchecked((self.back - 1) * WORD_BITS + bit) });
+            }
+            self.back -= 1;
+        }
+        None
+    }
+}
+
+impl<K: BoundedKey, const N: usize> ExactSizeIterator for Iter<K, N> {}
+impl<K: BoundedKey, const N: usize> FusedIterator for Iter<K, N> {}
+
+// === Serde ===
+
+#[cfg(feature = "serde1")]
+mod serde {
+    use core::fmt::{self, Formatter};
+    use core::marker::PhantomData;
+
+    use serde1::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde1::ser::{Serialize, Serializer};
+
+    use super::BoundedSet;
+    use crate::BoundedKey;
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde1")))]
+    impl<K: BoundedKey + Serialize, const N: usize> Serialize for BoundedSet<K, N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self)
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde1")))]
+    impl<'de, K: BoundedKey + Deserialize<'de>, const N: usize> Deserialize<'de> for BoundedSet<K, N> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct SetVisitor<K, const N: usize>(PhantomData<K>);
+
+            impl<'de, K: BoundedKey + Deserialize<'de>, const N: usize> Visitor<'de> for SetVisitor<K, N> {
+                type Value = BoundedSet<K, N>;
+
+                fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.write_str("a sequence of bounded integers")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let mut set = BoundedSet::new();
+                    while let Some(key) = seq.next_element()? {
+                        set.insert(key);
+                    }
+                    Ok(set)
+                }
+            }
+
+            deserializer.deserialize_seq(SetVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "types", feature = "macro"))]
+mod tests {
+    use super::BoundedSet;
+
+    #[test]
+    #[cfg(feature = "types")]
+    fn insert_remove() {
+        type Key = crate::BoundedI16<-10, 100>;
+        let mut set = BoundedSet::<Key, 2>::new();
+        assert!(set.is_empty());
+
+        assert!(set.insert(Key::MIN));
+        assert!(set.insert(Key::new(60).unwrap()));
+        assert!(set.insert(Key::MAX));
+        assert!(!set.insert(Key::MAX));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(Key::new(60).unwrap()));
+        assert!(!set.contains(Key::new(59).unwrap()));
+
+        assert!(set.remove(Key::new(60).unwrap()));
+        assert!(!set.remove(Key::new(60).unwrap()));
+        assert_eq!(set.first(), Some(Key::MIN));
+        assert_eq!(set.last(), Some(Key::MAX));
+
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!(set.first(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "types")]
+    fn iter() {
+        type Key = crate::BoundedU8<0, 199>;
+        let set = (0..200)
+            .filter(|n| n % 3 == 0)
+            .map(|n| Key::new(n).unwrap())
+            .collect::<BoundedSet<Key, 4>>();
+
+        assert_eq!(set.len(), 67);
+        assert_eq!(set.iter().len(), 67);
+        assert!(set.iter().map(Key::get).eq((0..200).step_by(3)));
+        assert!(set.iter().rev().map(Key::get).eq((0..200).step_by(3).rev()));
+
+        let mut iter = set.iter();
+        assert_eq!(iter.next(), Some(Key::new(0).unwrap()));
+        assert_eq!(iter.next_back(), Some(Key::new(198).unwrap()));
+        assert_eq!(iter.len(), 65);
+        assert_eq!(iter.count(), 65);
+    }
+
+    #[test]
+    #[cfg(feature = "types")]
+    fn algebra() {
+        type Key = crate::BoundedU8<1, 70>;
+        let set = |keys: &[u8]| -> BoundedSet<Key, 2> {
+            keys.iter().map(|&n| Key::new(n).unwrap()).collect()
+        };
+        let a = set(&[1, 2, 3, 65, 70]);
+        let b = set(&[3, 4, 65]);
+
+        assert_eq!(a.union(&b), set(&[1, 2, 3, 4, 65, 70]));
+        assert_eq!(a.intersection(&b), set(&[3, 65]));
+        assert_eq!(a.difference(&b), set(&[1, 2, 70]));
+        assert_eq!(a.symmetric_difference(&b), set(&[1, 2, 4, 70]));
+        assert!(set(&[3, 65]).is_subset(&a));
+        assert!(a.is_superset(&set(&[3, 65])));
+        assert!(!a.is_subset(&b));
+        assert!(a.is_disjoint(&set(&[4, 5])));
+
+        let full = BoundedSet::<Key, 2>::full();
+        assert_eq!(full.len(), 70);
+        assert_eq!(full.last(), Some(Key::MAX));
+        assert_eq!(!a, full - a);
+        assert_eq!((!a).len(), 65);
+    }
+
+    #[test]
+    #[cfg(feature = "macro")]
+    fn macro_key() {
+        crate::bounded_integer! {
+            enum Key { -1..=1 }
+        }
+
+        let mut set = BoundedSet::<Key, 1>::new();
+        set.extend([Key::N1, Key::P1]);
+        assert!(set.iter().eq([Key::N1, Key::P1]));
+        assert!((!set).iter().eq([Key::Z]));
+    }
+}
@@ -0,0 +1,103 @@
+use core::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Slicing of slices and strings by ranges of bounded integers.
+///
+/// This is the range equivalent of indexing by a bounded integer. It cannot be provided through
+/// the `[]` operator, because Rust does not allow implementing [`Index`](core::ops::Index) for a
+/// range of a type from another crate; instead, the methods of this trait behave exactly like the
+/// operator, including panicking when the range is out of bounds or decreasing. Because of
+/// auto-deref they also work on `Vec<T>`, `String` and arrays. `VecDeque<T>` is not contiguous and
+/// so can't be sliced; use [`VecDeque::range`] instead.
+///
+/// It is implemented for [`Range`], [`RangeInclusive`], [`RangeFrom`], [`RangeTo`] and
+/// [`RangeToInclusive`] of all non-negative bounded integers.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+#[cfg_attr(not(feature = "types"), doc = "# #[cfg(any())] {")]
+/// use bounded_integer::{BoundedUsize, SliceBounded};
+///
+/// let buf = [1, 2, 3, 4, 5, 6, 7, 8];
+/// let start = BoundedUsize::<0, 7>::new(2).unwrap();
+/// let end = BoundedUsize::<0, 7>::new(5).unwrap();
+/// assert_eq!(buf.slice_bounded(start..end), [3, 4, 5]);
+/// assert_eq!(buf.slice_bounded(..=end), [1, 2, 3, 4, 5, 6]);
+/// assert_eq!("bounded".slice_bounded(start..), "unded");
+#[cfg_attr(not(feature = "types"), doc = "# }")]
+/// ```
+///
+/// [`VecDeque::range`]: https://doc.rust-lang.org/stable/alloc/collections/struct.VecDeque.html#method.range
+pub trait SliceBounded<R> {
+    /// The type of the subslice.
+    type Output: ?Sized;
+
+    /// Returns a shared reference to the subslice in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start is greater than its end, or, for
+    /// strings, if either end does not lie on a `char` boundary.
+    #[must_use]
+    fn slice_bounded(&self, range: R) -> &Self::Output;
+
+    /// Returns a mutable reference to the subslice in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start is greater than its end, or, for
+    /// strings, if either end does not lie on a `char` boundary.
+    #[must_use]
+    fn slice_bounded_mut(&mut self, range: R) -> &mut Self::Output;
+}
+
+/// Bounded integers that can be used as the ends of ranges in [`SliceBounded`].
+///
+/// This is an implementation detail of the crate and is implemented for all non-negative bounded
+/// integers.
+#[doc(hidden)]
+pub trait SliceIndex: Copy {
+    /// Converts the index to a `usize`, which is `usize::MAX` if it doesn't fit.
+    fn to_slice_index(self) -> usize;
+}
+
+macro_rules! impl_slice {
+    ($($range:ident($r:ident) => $to_usize:expr,)*) => { $(
+        impl<I: SliceIndex, T> SliceBounded<$range<I>> for [T] {
+            type Output = [T];
+
+            #[inline]
+            fn slice_bounded(&self, $r: $range<I>) -> &Self::Output {
+                &self[$to_usize]
+            }
+
+            #[inline]
+            fn slice_bounded_mut(&mut self, $r: $range<I>) -> &mut Self::Output {
+                &mut self[$to_usize]
+            }
+        }
+
+        impl<I: SliceIndex> SliceBounded<$range<I>> for str {
+            type Output = str;
+
+            #[inline]
+            fn slice_bounded(&self, $r: $range<I>) -> &Self::Output {
+                &self[$to_usize]
+            }
+
+            #[inline]
+            fn slice_bounded_mut(&mut self, $r: $range<I>) -> &mut Self::Output {
+                &mut self[$to_usize]
+            }
+        }
+    )* };
+}
+
+impl_slice! {
+    Range(r) => r.start.to_slice_index()..r.end.to_slice_index(),
+    RangeInclusive(r) => r.start().to_slice_index()..=r.end().to_slice_index(),
+    RangeFrom(r) => r.start.to_slice_index()..,
+    RangeTo(r) => ..r.end.to_slice_index(),
+    RangeToInclusive(r) => ..=r.end.to_slice_index(),
+}
@@ -0,0 +1,229 @@
+//! The archived forms of the const-generic bounded integers, for use with rkyv.
+
+macro_rules! define_archived_bounded_integers {
+    ($($name:ident $bounded:ident $inner:ident $fixed:ty,)*) => { $(
+        mod $inner {
+            use core::cmp::Ordering;
+            use core::fmt::{self, Debug, Display, Formatter};
+            use core::hash::{Hash, Hasher};
+
+            use rkyv08::bytecheck::CheckBytes;
+            use rkyv08::rancor::{fail, Fallible, Source};
+            use rkyv08::traits::NoUndef;
+            use rkyv08::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+            use crate::rkyv::ArchiveError;
+            use crate::types::$bounded as Bounded;
+
+            type Inner = core::primitive::$inner;
+
+            /// The integer type that `Inner` is archived as.
+            type Fixed = $fixed;
+
+            #[doc = "An archived"]
+            #[doc = concat!("[`", stringify!($bounded), "`](crate::", stringify!($bounded), ").")]
+            ///
+            /// It stores the value with the byte order and size chosen by rkyv's feature flags.
+            /// Validating an archive with `bytecheck` rejects values that are out of range, so
+            /// reading it never fails.
+            #[repr(transparent)]
+            #[derive(Clone, Copy)]
+            pub struct $name<const MIN: Inner, const MAX: Inner>(Archived<Fixed>);
+
+            // SAFETY: `Archived<Fixed>` is portable and this type is a transparent wrapper of it.
+            unsafe impl<const MIN: Inner, const MAX: Inner> Portable for $name<MIN, MAX> {}
+
+            // SAFETY: `Archived<Fixed>` has no undefined bytes and this type is a transparent
+            // wrapper of it.
+            unsafe impl<const MIN: Inner, const MAX: Inner> NoUndef for $name<MIN, MAX> {}
+
+            impl<const MIN: Inner, const MAX: Inner> $name<MIN, MAX> {
+                /// Gets the value of the archived integer.
+                #[must_use]
+                #[inline]
+                #[allow(clippy::unnecessary_cast)]
+                pub fn get(self) -> Inner {
+                    Fixed::from(self.0) as Inner
+                }
+
+                /// Gets the value of the archived integer as a bounded integer.
+                #[must_use]
+                #[inline]
+                pub fn get_bounded(self) -> Bounded<MIN, MAX> {
+                    // SAFETY: Archived bounded integers are always in range, since they are
+                    // either written from a bounded integer or validated.
+                    unsafe { Bounded::new_unchecked(self.get()) }
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Archive for Bounded<MIN, MAX> {
+                type Archived = $name<MIN, MAX>;
+                type Resolver = ();
+
+                #[inline]
+                #[allow(clippy::unnecessary_cast)]
+                fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+                    // Serialization checked that the value fits in `Fixed`.
+                    out.write($name(Archived::<Fixed>::from(self.get() as Fixed)));
+                }
+            }
+
+            impl<S, const MIN: Inner, const MAX: Inner> Serialize<S> for Bounded<MIN, MAX>
+            where
+                S: Fallible + ?Sized,
+                S::Error: Source,
+            {
+                #[allow(clippy::useless_conversion)]
+                fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                    match Fixed::try_from(self.get()) {
+                        Ok(_) => Ok(()),
+                        Err(_) => fail!(ArchiveError::TooLarge),
+                    }
+                }
+            }
+
+            impl<D, const MIN: Inner, const MAX: Inner> Deserialize<Bounded<MIN, MAX>, D>
+                for $name<MIN, MAX>
+            where
+                D: Fallible + ?Sized,
+            {
+                #[inline]
+                fn deserialize(&self, _: &mut D) -> Result<Bounded<MIN, MAX>, D::Error> {
+                    Ok(self.get_bounded())
+                }
+            }
+
+            // SAFETY: `check_bytes` only returns `Ok` if the inner value is valid and in range.
+            unsafe impl<C, const MIN: Inner, const MAX: Inner> CheckBytes<C> for $name<MIN, MAX>
+            where
+                C: Fallible + ?Sized,
+                C::Error: Source,
+                Archived<Fixed>: CheckBytes<C>,
+            {
+                #[allow(clippy::useless_conversion)]
+                unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+                    let inner = value.cast::<Archived<Fixed>>();
+                    // SAFETY: The caller guarantees that `value` is aligned and points to enough
+                    // bytes for `Self`, which is a transparent wrapper of `Archived<Fixed>`.
+                    unsafe { Archived::<Fixed>::check_bytes(inner, context)? };
+                    // SAFETY: We just checked that the inner value is valid.
+                    let n = Fixed::from(unsafe { *inner });
+                    match Inner::try_from(n) {
+                        Ok(n) if Bounded::<MIN, MAX>::in_range(n) => Ok(()),
+                        _ => fail!(ArchiveError::OutOfRange),
+                    }
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> From<$name<MIN, MAX>> for Bounded<MIN, MAX> {
+                fn from(archived: $name<MIN, MAX>) -> Self {
+                    archived.get_bounded()
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> From<$name<MIN, MAX>> for Inner {
+                fn from(archived: $name<MIN, MAX>) -> Self {
+                    archived.get()
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> PartialEq for $name<MIN, MAX> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.get() == other.get()
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Eq for $name<MIN, MAX> {}
+
+            impl<const MIN: Inner, const MAX: Inner> PartialEq<Inner> for $name<MIN, MAX> {
+                fn eq(&self, other: &Inner) -> bool {
+                    self.get() == *other
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> PartialEq<Bounded<MIN, MAX>>
+                for $name<MIN, MAX>
+            {
+                fn eq(&self, other: &Bounded<MIN, MAX>) -> bool {
+                    self.get() == other.get()
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> PartialOrd for $name<MIN, MAX> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Ord for $name<MIN, MAX> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.get().cmp(&other.get())
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Hash for $name<MIN, MAX> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.get().hash(state);
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Debug for $name<MIN, MAX> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    Debug::fmt(&self.get(), f)
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Display for $name<MIN, MAX> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    Display::fmt(&self.get(), f)
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::{$name as Archived, Bounded, Inner};
+                use rkyv08::rancor::Error;
+
+                #[test]
+                fn round_trip() {
+                    let values = [Bounded::<3, 100>::new(5).unwrap(), Bounded::MAX];
+                    let bytes = rkyv08::to_bytes::<Error>(&values).unwrap();
+                    let archived = rkyv08::access::<[Archived<3, 100>; 2], Error>(&bytes).unwrap();
+                    assert_eq!(archived[0], 5);
+                    assert_eq!(archived[1], Bounded::<3, 100>::MAX);
+                    assert!(archived[0] < archived[1]);
+                    assert_eq!(archived[0].get_bounded(), 5);
+                    assert_eq!(Inner::from(archived[1]), 100);
+                    let deserialized = rkyv08::deserialize::<[Bounded<3, 100>; 2], Error>(archived);
+                    assert_eq!(deserialized.unwrap(), values);
+                }
+
+                #[test]
+                fn validation() {
+                    let bytes = rkyv08::to_bytes::<Error>(&(101 as Inner)).unwrap();
+                    assert!(rkyv08::access::<Archived<3, 100>, Error>(&bytes).is_err());
+                    let bytes = rkyv08::to_bytes::<Error>(&(2 as Inner)).unwrap();
+                    assert!(rkyv08::access::<Archived<3, 100>, Error>(&bytes).is_err());
+                    let bytes = rkyv08::to_bytes::<Error>(&(3 as Inner)).unwrap();
+                    assert_eq!(*rkyv08::access::<Archived<3, 100>, Error>(&bytes).unwrap(), 3);
+                }
+            }
+        }
+        pub use self::$inner::$name;
+    )* };
+}
+
+define_archived_bounded_integers! {
+    ArchivedBoundedU8 BoundedU8 u8 u8,
+    ArchivedBoundedU16 BoundedU16 u16 u16,
+    ArchivedBoundedU32 BoundedU32 u32 u32,
+    ArchivedBoundedU64 BoundedU64 u64 u64,
+    ArchivedBoundedU128 BoundedU128 u128 u128,
+    ArchivedBoundedUsize BoundedUsize usize rkyv08::primitive::FixedUsize,
+    ArchivedBoundedI8 BoundedI8 i8 i8,
+    ArchivedBoundedI16 BoundedI16 i16 i16,
+    ArchivedBoundedI32 BoundedI32 i32 i32,
+    ArchivedBoundedI64 BoundedI64 i64 i64,
+    ArchivedBoundedI128 BoundedI128 i128 i128,
+    ArchivedBoundedIsize BoundedIsize isize rkyv08::primitive::FixedIsize,
+}
@@ -0,0 +1,426 @@
+//! Atomic versions of the unsigned const-generic bounded integers.
+
+use core::sync::atomic::Ordering;
+
+/// The strongest ordering that a failed compare-and-swap can use for the given ordering.
+fn failure_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::Acquire | Ordering::AcqRel => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
+
+macro_rules! define_atomic_bounded_integers {
+    ($($name:ident $bounded:ident $atomic:ident $inner:ident $size:literal,)*) => { $(
+        #[cfg(target_has_atomic = $size)]
+        mod $inner {
+            use core::fmt::{self, Debug, Formatter};
+            use core::sync::atomic::{$atomic, Ordering};
+
+            use super::failure_ordering;
+            use crate::types::$bounded as Bounded;
+
+            type Inner = core::primitive::$inner;
+
+            #[doc = "A"]
+            #[doc = concat!("[`", stringify!($bounded), "`](crate::", stringify!($bounded), ")")]
+            #[doc = "which can be safely shared between threads."]
+            ///
+            /// Every operation keeps the stored value in the range `MIN..=MAX`: read-modify-write
+            /// operations that would leave it either fail, saturate or wrap around within the
+            /// range, rather than wrapping around the whole primitive like
+            #[doc = concat!("[`", stringify!($atomic), "`]")]
+            /// does.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+            #[doc = concat!("use bounded_integer::", stringify!($name), ";")]
+            /// use core::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let connections = ", stringify!($name), "::<0, 3>::new_min();")]
+            /// assert!(connections.fetch_checked_add(3, Ordering::AcqRel, Ordering::Acquire).is_ok());
+            /// assert!(connections.fetch_checked_add(1, Ordering::AcqRel, Ordering::Acquire).is_err());
+            /// assert_eq!(connections.fetch_saturating_sub(5, Ordering::AcqRel), 3);
+            /// assert_eq!(connections.load(Ordering::Acquire), 0);
+            /// ```
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
+            #[repr(transparent)]
+            pub struct Atomic<const MIN: Inner, const MAX: Inner>($atomic);
+
+            impl<const MIN: Inner, const MAX: Inner> Atomic<MIN, MAX> {
+                /// Creates a new atomic bounded integer.
+                #[must_use]
+                #[inline]
+                pub const fn new(value: Bounded<MIN, MAX>) -> Self {
+                    Self($atomic::new(value.get()))
+                }
+
+                /// Creates a new atomic bounded integer holding [`MIN`](Bounded::MIN).
+                #[must_use]
+                #[inline]
+                pub const fn new_min() -> Self {
+                    Self::new(Bounded::MIN)
+                }
+
+                /// Creates a new atomic bounded integer holding [`MAX`](Bounded::MAX).
+                #[must_use]
+                #[inline]
+                pub const fn new_max() -> Self {
+                    Self::new(Bounded::MAX)
+                }
+
+                /// Returns a mutable reference to the underlying bounded integer.
+                ///
+                /// This is safe because the mutable reference guarantees that no other threads are
+                /// concurrently accessing the atomic data.
+                #[must_use]
+                #[inline]
+                pub fn get_mut(&mut self) -> &mut Bounded<MIN, MAX> {
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { Bounded::new_mut_unchecked(self.0.get_mut()) }
+                }
+
+                /// Consumes the atomic and returns the contained value.
+                #[must_use]
+                #[inline]
+                pub fn into_inner(self) -> Bounded<MIN, MAX> {
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { Bounded::new_unchecked(self.0.into_inner()) }
+                }
+
+                /// Loads the value of the atomic bounded integer.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `order` is [`Release`](Ordering::Release) or
+                /// [`AcqRel`](Ordering::AcqRel).
+                #[must_use]
+                #[inline]
+                pub fn load(&self, order: Ordering) -> Bounded<MIN, MAX> {
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { Bounded::new_unchecked(self.0.load(order)) }
+                }
+
+                /// Stores a value into the atomic bounded integer.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `order` is [`Acquire`](Ordering::Acquire) or
+                /// [`AcqRel`](Ordering::AcqRel).
+                #[inline]
+                pub fn store(&self, value: Bounded<MIN, MAX>, order: Ordering) {
+                    self.0.store(value.get(), order);
+                }
+
+                /// Stores a value into the atomic bounded integer, returning the previous value.
+                #[inline]
+                pub fn swap(&self, value: Bounded<MIN, MAX>, order: Ordering) -> Bounded<MIN, MAX> {
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { Bounded::new_unchecked(self.0.swap(value.get(), order)) }
+                }
+
+                /// Stores a value into the atomic bounded integer if the current value is the same
+                /// as `current`.
+                ///
+                /// The return value is a result indicating whether the new value was written and
+                /// containing the previous value. See
+                #[doc = concat!("[`", stringify!($atomic), "::compare_exchange`]")]
+                /// for the meaning of the orderings.
+                ///
+                /// # Errors
+                ///
+                /// Returns the current value if it is not equal to `current`.
+                #[inline]
+                pub fn compare_exchange(
+                    &self,
+                    current: Bounded<MIN, MAX>,
+                    new: Bounded<MIN, MAX>,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Bounded<MIN, MAX>, Bounded<MIN, MAX>> {
+                    let result = self.0.compare_exchange(current.get(), new.get(), success, failure);
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { wrap_result(result) }
+                }
+
+                /// Stores a value into the atomic bounded integer if the current value is the same
+                /// as `current`.
+                ///
+                /// Unlike [`compare_exchange`](Self::compare_exchange), this function is allowed to
+                /// spuriously fail even when the comparison succeeds, which can result in more
+                /// efficient code on some platforms.
+                ///
+                /// # Errors
+                ///
+                /// Returns the current value if it is not equal to `current`, or spuriously.
+                #[inline]
+                pub fn compare_exchange_weak(
+                    &self,
+                    current: Bounded<MIN, MAX>,
+                    new: Bounded<MIN, MAX>,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Bounded<MIN, MAX>, Bounded<MIN, MAX>> {
+                    let result =
+                        self.0.compare_exchange_weak(current.get(), new.get(), success, failure);
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { wrap_result(result) }
+                }
+
+                /// Fetches the value, and applies a function to it that returns an optional new
+                /// value, retrying if another thread changes the value in the meantime.
+                ///
+                /// See
+                #[doc = concat!("[`", stringify!($atomic), "::fetch_update`]")]
+                /// for the meaning of the orderings.
+                ///
+                /// # Errors
+                ///
+                /// Returns the current value if the function returned [`None`].
+                #[inline]
+                pub fn fetch_update<F>(
+                    &self,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                    mut f: F,
+                ) -> Result<Bounded<MIN, MAX>, Bounded<MIN, MAX>>
+                where
+                    F: FnMut(Bounded<MIN, MAX>) -> Option<Bounded<MIN, MAX>>,
+                {
+                    let result = self.0.fetch_update(set_order, fetch_order, |n| {
+                        // SAFETY: The atomic only ever holds values in range.
+                        f(unsafe { Bounded::new_unchecked(n) }).map(Bounded::get)
+                    });
+                    // SAFETY: The atomic only ever holds values in range.
+                    unsafe { wrap_result(result) }
+                }
+
+                /// Adds to the current value if the result is in range, returning the previous
+                /// value.
+                ///
+                /// # Errors
+                ///
+                /// Returns the current value if the result would be out of range, in which case
+                /// nothing is stored.
+                #[inline]
+                pub fn fetch_checked_add(
+                    &self,
+                    n: Inner,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                ) -> Result<Bounded<MIN, MAX>, Bounded<MIN, MAX>> {
+                    self.fetch_update(set_order, fetch_order, |v| v.checked_add(n))
+                }
+
+                /// Subtracts from the current value if the result is in range, returning the
+                /// previous value.
+                ///
+                /// # Errors
+                ///
+                /// Returns the current value if the result would be out of range, in which case
+                /// nothing is stored.
+                #[inline]
+                pub fn fetch_checked_sub(
+                    &self,
+                    n: Inner,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                ) -> Result<Bounded<MIN, MAX>, Bounded<MIN, MAX>> {
+                    self.fetch_update(set_order, fetch_order, |v| v.checked_sub(n))
+                }
+
+                /// Adds to the current value, saturating at [`MAX`](Bounded::MAX), and returns the
+                /// previous value.
+                #[inline]
+                pub fn fetch_saturating_add(&self, n: Inner, order: Ordering) -> Bounded<MIN, MAX> {
+                    self.fetch_always(order, |v| v.saturating_add(n))
+                }
+
+                /// Subtracts from the current value, saturating at [`MIN`](Bounded::MIN), and
+                /// returns the previous value.
+                #[inline]
+                pub fn fetch_saturating_sub(&self, n: Inner, order: Ordering) -> Bounded<MIN, MAX> {
+                    self.fetch_always(order, |v| v.saturating_sub(n))
+                }
+
+                /// Adds to the current value, wrapping around from [`MAX`](Bounded::MAX) to
+                /// [`MIN`](Bounded::MIN), and returns the previous value.
+                #[inline]
+                pub fn fetch_wrapping_add(&self, n: Inner, order: Ordering) -> Bounded<MIN, MAX> {
+                    let n = n as u128 % Self::range_len();
+                    self.fetch_always(order, |v| Self::wrap((v.get() - MIN) as u128 + n))
+                }
+
+                /// Subtracts from the current value, wrapping around from [`MIN`](Bounded::MIN) to
+                /// [`MAX`](Bounded::MAX), and returns the previous value.
+                #[inline]
+                pub fn fetch_wrapping_sub(&self, n: Inner, order: Ordering) -> Bounded<MIN, MAX> {
+                    let n = Self::range_len() - n as u128 % Self::range_len();
+                    self.fetch_always(order, |v| Self::wrap((v.get() - MIN) as u128 + n))
+                }
+
+                /// Applies an infallible update, returning the previous value.
+                fn fetch_always(
+                    &self,
+                    order: Ordering,
+                    mut f: impl FnMut(Bounded<MIN, MAX>) -> Bounded<MIN, MAX>,
+                ) -> Bounded<MIN, MAX> {
+                    match self.fetch_update(order, failure_ordering(order), |v| Some(f(v))) {
+                        Ok(v) | Err(v) => v,
+                    }
+                }
+
+                /// The number of values in the range, which fits in a `u128` for all types that
+                /// have atomics.
+                fn range_len() -> u128 {
+                    (MAX - MIN) as u128 + 1
+                }
+
+                /// Converts an offset from `MIN` to a bounded integer, wrapping around the range.
+                fn wrap(offset: u128) -> Bounded<MIN, MAX> {
+                    // The remainder is at most `MAX - MIN`, so this can't truncate or overflow.
+                    let n = (offset % Self::range_len()) as Inner + MIN;
+                    // SAFETY: `n` lies in `MIN..=MAX`.
+                    unsafe { Bounded::new_unchecked(n) }
+                }
+            }
+
+            /// Converts the result of an atomic operation on the inner integer.
+            ///
+            /// # Safety
+            ///
+            /// Both values must be in range.
+            unsafe fn wrap_result<const MIN: Inner, const MAX: Inner>(
+                result: Result<Inner, Inner>,
+            ) -> Result<Bounded<MIN, MAX>, Bounded<MIN, MAX>> {
+                match result {
+                    Ok(n) => Ok(Bounded::new_unchecked(n)),
+                    Err(n) => Err(Bounded::new_unchecked(n)),
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> From<Bounded<MIN, MAX>> for Atomic<MIN, MAX> {
+                #[inline]
+                fn from(value: Bounded<MIN, MAX>) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Debug for Atomic<MIN, MAX> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    Debug::fmt(&self.load(Ordering::Relaxed), f)
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use core::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed, Release, SeqCst};
+
+                type Bounded = crate::types::$bounded<3, 10>;
+                type Atomic = super::Atomic<3, 10>;
+
+                fn b(n: super::Inner) -> Bounded {
+                    Bounded::new(n).unwrap()
+                }
+
+                #[test]
+                fn load_store() {
+                    let mut atomic = Atomic::new_min();
+                    assert_eq!(atomic.load(SeqCst), 3);
+                    atomic.store(b(7), SeqCst);
+                    assert_eq!(atomic.swap(b(9), SeqCst), 7);
+                    assert_eq!(atomic.compare_exchange(b(8), b(4), SeqCst, SeqCst), Err(b(9)));
+                    assert_eq!(atomic.compare_exchange(b(9), b(4), SeqCst, SeqCst), Ok(b(9)));
+                    *atomic.get_mut() = Bounded::MAX;
+                    assert_eq!(atomic.into_inner(), 10);
+                    assert_eq!(Atomic::from(b(5)).into_inner(), 5);
+                    assert_eq!(Atomic::new_max().into_inner(), 10);
+                }
+
+                #[test]
+                fn fetch_update() {
+                    let atomic = Atomic::new(b(5));
+                    assert_eq!(atomic.fetch_update(AcqRel, Acquire, |v| v.checked_mul(2)), Ok(b(5)));
+                    assert_eq!(atomic.fetch_update(AcqRel, Acquire, |v| v.checked_mul(2)), Err(b(10)));
+                    assert_eq!(atomic.load(Relaxed), 10);
+                }
+
+                #[test]
+                fn arithmetic() {
+                    let atomic = Atomic::new(b(5));
+                    assert_eq!(atomic.fetch_checked_add(5, AcqRel, Acquire), Ok(b(5)));
+                    assert_eq!(atomic.fetch_checked_add(1, AcqRel, Acquire), Err(b(10)));
+                    assert_eq!(atomic.fetch_checked_sub(8, AcqRel, Acquire), Err(b(10)));
+                    assert_eq!(atomic.fetch_checked_sub(7, AcqRel, Acquire), Ok(b(10)));
+                    assert_eq!(atomic.load(Relaxed), 3);
+
+                    assert_eq!(atomic.fetch_saturating_sub(1, Release), 3);
+                    assert_eq!(atomic.fetch_saturating_add(100, Release), 3);
+                    assert_eq!(atomic.fetch_saturating_add(1, Release), 10);
+                    assert_eq!(atomic.load(Relaxed), 10);
+
+                    assert_eq!(atomic.fetch_wrapping_add(1, AcqRel), 10);
+                    assert_eq!(atomic.load(Relaxed), 3);
+                    assert_eq!(atomic.fetch_wrapping_sub(1, AcqRel), 3);
+                    assert_eq!(atomic.load(Relaxed), 10);
+                    assert_eq!(atomic.fetch_wrapping_add(8 * 20 + 2, AcqRel), 10);
+                    assert_eq!(atomic.load(Relaxed), 4);
+                    assert_eq!(atomic.fetch_wrapping_sub(8 * 20 + 2, AcqRel), 4);
+                    assert_eq!(atomic.load(Relaxed), 10);
+                    assert_eq!(atomic.fetch_wrapping_add(super::Inner::MAX, AcqRel), 10);
+
+                    let full = super::Atomic::<0, { super::Inner::MAX }>::new_max();
+                    full.fetch_wrapping_add(1, Relaxed);
+                    assert_eq!(full.load(Relaxed), 0);
+                    full.fetch_wrapping_sub(1, Relaxed);
+                    assert_eq!(full.load(Relaxed), super::Inner::MAX);
+                }
+
+                #[test]
+                #[cfg(feature = "std")]
+                fn threads() {
+                    use std::sync::atomic::AtomicUsize;
+                    use std::thread;
+
+                    let atomic = super::Atomic::<0, 100>::new_min();
+                    let rejected = AtomicUsize::new(0);
+                    let wrapping = super::Atomic::<0, 6>::new_min();
+
+                    thread::scope(|s| {
+                        for _ in 0..8 {
+                            s.spawn(|| {
+                                for _ in 0..1000 {
+                                    if atomic.fetch_checked_add(1, AcqRel, Acquire).is_err() {
+                                        rejected.fetch_add(1, Relaxed);
+                                    }
+                                    atomic.fetch_saturating_sub(1, AcqRel);
+                                    atomic.fetch_saturating_add(2, AcqRel);
+                                    assert!(atomic.load(Acquire) <= 100);
+                                    wrapping.fetch_wrapping_add(3, AcqRel);
+                                }
+                            });
+                        }
+                    });
+
+                    assert_eq!(atomic.load(Relaxed), 100);
+                    assert!(rejected.load(Relaxed) > 0);
+                    assert_eq!(wrapping.load(Relaxed), (8_usize * 1000 * 3 % 7) as super::Inner);
+                }
+            }
+        }
+
+        #[cfg(target_has_atomic = $size)]
+        pub use self::$inner::Atomic as $name;
+    )* };
+}
+
+define_atomic_bounded_integers! {
+    AtomicBoundedU8 BoundedU8 AtomicU8 u8 "8",
+    AtomicBoundedU16 BoundedU16 AtomicU16 u16 "16",
+    AtomicBoundedU32 BoundedU32 AtomicU32 u32 "32",
+    AtomicBoundedU64 BoundedU64 AtomicU64 u64 "64",
+    AtomicBoundedUsize BoundedUsize AtomicUsize usize "ptr",
+}
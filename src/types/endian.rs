@@ -0,0 +1,338 @@
+//! Const-generic bounded integers stored with a fixed byte order and no alignment requirement.
+
+macro_rules! define_endian_bounded_integers {
+    ($(
+        $name:ident $bounded:ident $inner:ident $module:ident
+        $from_bytes:ident $to_bytes:ident $order:literal,
+    )*) => { $(
+        mod $module {
+            use core::cmp::Ordering;
+            use core::fmt::{self, Debug, Display, Formatter};
+            use core::hash::{Hash, Hasher};
+            use core::mem;
+
+            use crate::parse::ParseError;
+            use crate::types::$bounded as Bounded;
+
+            type Inner = core::primitive::$inner;
+
+            type Bytes = [u8; mem::size_of::<Inner>()];
+
+            #[doc = "A"]
+            #[doc = concat!("[`", stringify!($bounded), "`](crate::", stringify!($bounded), ")")]
+            #[doc = concat!("stored in ", $order, "-endian byte order with an alignment of 1.")]
+            ///
+            /// This is useful for fields of packed structures and wire formats, where the native
+            /// bounded integer can't be used because of its byte order or alignment. The value is
+            /// checked to be in range whenever it is written, so reading it never fails.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+            #[doc = concat!("use bounded_integer::{", stringify!($bounded), ", ", stringify!($name), "};")]
+            ///
+            /// #[repr(C, packed)]
+            /// struct Header {
+            ///     version: u8,
+            #[doc = concat!("    port: ", stringify!($name), "<1, 100>,")]
+            /// }
+            ///
+            #[doc = concat!("let mut header = Header { version: 1, port: ", stringify!($name), "::new(80).unwrap() };")]
+            /// assert_eq!(header.port.get(), 80);
+            #[doc = concat!("assert_eq!(header.port.to_bytes(), 80_", stringify!($inner), ".", stringify!($to_bytes), "());")]
+            /// assert!(header.port.set(101).is_err());
+            ///
+            #[doc = concat!("let port: ", stringify!($bounded), "<1, 100> = header.port.into();")]
+            /// assert_eq!(port, 80);
+            /// ```
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
+            #[repr(transparent)]
+            #[derive(Clone, Copy)]
+            #[cfg_attr(
+                feature = "zerocopy08",
+                derive(
+                    zerocopy08::IntoBytes,
+                    zerocopy08::KnownLayout,
+                    zerocopy08::Immutable,
+                    zerocopy08::Unaligned,
+                ),
+                zerocopy(crate = "zerocopy08"),
+            )]
+            pub struct Endian<const MIN: Inner, const MAX: Inner>(Bytes);
+
+            impl<const MIN: Inner, const MAX: Inner> Endian<MIN, MAX> {
+                /// The smallest value this bounded integer can contain.
+                pub const MIN_VALUE: Inner = MIN;
+                /// The largest value that this bounded integer can contain.
+                pub const MAX_VALUE: Inner = MAX;
+
+                /// The smallest value of the bounded integer.
+                pub const MIN: Self = Self::from_bounded(Bounded::MIN);
+                /// The largest value of the bounded integer.
+                pub const MAX: Self = Self::from_bounded(Bounded::MAX);
+
+                /// Creates a bounded integer if the given value is within the range
+                /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)].
+                #[must_use]
+                #[inline]
+                pub const fn new(n: Inner) -> Option<Self> {
+                    match Bounded::new(n) {
+                        Some(bounded) => Some(Self::from_bounded(bounded)),
+                        None => None,
+                    }
+                }
+
+                /// Converts a native-endian bounded integer to this byte order.
+                #[must_use]
+                #[inline]
+                pub const fn from_bounded(bounded: Bounded<MIN, MAX>) -> Self {
+                    Self(bounded.get().$to_bytes())
+                }
+
+                /// Creates a bounded integer from its bytes.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error of kind [`BelowMin`](crate::ParseErrorKind::BelowMin) or
+                /// [`AboveMax`](crate::ParseErrorKind::AboveMax) if the value is out of range.
+                #[inline]
+                pub const fn try_from_bytes(bytes: Bytes) -> Result<Self, ParseError> {
+                    let n = Inner::$from_bytes(bytes);
+                    if n < MIN {
+                        Err(crate::parse::error_below_min())
+                    } else if n > MAX {
+                        Err(crate::parse::error_above_max())
+                    } else {
+                        Ok(Self(bytes))
+                    }
+                }
+
+                /// Returns the bytes of the bounded integer.
+                #[must_use]
+                #[inline]
+                pub const fn to_bytes(self) -> Bytes {
+                    self.0
+                }
+
+                /// Returns the value of the bounded integer as a primitive type.
+                #[must_use]
+                #[inline]
+                pub const fn get(self) -> Inner {
+                    Inner::$from_bytes(self.0)
+                }
+
+                /// Returns the value of the bounded integer as a native-endian bounded integer.
+                #[must_use]
+                #[inline]
+                pub const fn get_bounded(self) -> Bounded<MIN, MAX> {
+                    // SAFETY: The value is checked to be in range whenever it is written.
+                    unsafe { Bounded::new_unchecked(self.get()) }
+                }
+
+                /// Sets the value of the bounded integer.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error of kind [`BelowMin`](crate::ParseErrorKind::BelowMin) or
+                /// [`AboveMax`](crate::ParseErrorKind::AboveMax) if the value is out of range, in
+                /// which case the bounded integer is left unchanged.
+                #[inline]
+                pub fn set(&mut self, n: Inner) -> Result<(), ParseError> {
+                    *self = Self::try_from_bytes(n.$to_bytes())?;
+                    Ok(())
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> From<Bounded<MIN, MAX>> for Endian<MIN, MAX> {
+                #[inline]
+                fn from(bounded: Bounded<MIN, MAX>) -> Self {
+                    Self::from_bounded(bounded)
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> From<Endian<MIN, MAX>> for Bounded<MIN, MAX> {
+                #[inline]
+                fn from(endian: Endian<MIN, MAX>) -> Self {
+                    endian.get_bounded()
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> From<Endian<MIN, MAX>> for Inner {
+                #[inline]
+                fn from(endian: Endian<MIN, MAX>) -> Self {
+                    endian.get()
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> PartialEq for Endian<MIN, MAX> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Eq for Endian<MIN, MAX> {}
+
+            impl<const MIN: Inner, const MAX: Inner> PartialEq<Inner> for Endian<MIN, MAX> {
+                #[inline]
+                fn eq(&self, other: &Inner) -> bool {
+                    self.get() == *other
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> PartialOrd for Endian<MIN, MAX> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Ord for Endian<MIN, MAX> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.get().cmp(&other.get())
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Hash for Endian<MIN, MAX> {
+                #[inline]
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.get().hash(state);
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Debug for Endian<MIN, MAX> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    Debug::fmt(&self.get(), f)
+                }
+            }
+
+            impl<const MIN: Inner, const MAX: Inner> Display for Endian<MIN, MAX> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    Display::fmt(&self.get(), f)
+                }
+            }
+
+            // === Bytemuck ===
+
+            #[cfg(feature = "bytemuck1")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "bytemuck1")))]
+            unsafe impl<const MIN: Inner, const MAX: Inner> bytemuck1::NoUninit for Endian<MIN, MAX> {}
+
+            #[cfg(feature = "bytemuck1")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "bytemuck1")))]
+            unsafe impl<const MIN: Inner, const MAX: Inner> bytemuck1::CheckedBitPattern
+                for Endian<MIN, MAX>
+            {
+                type Bits = Bytes;
+
+                #[inline]
+                fn is_valid_bit_pattern(bits: &Bytes) -> bool {
+                    Bounded::<MIN, MAX>::in_range(Inner::$from_bytes(*bits))
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::Inner;
+
+                type Endian = super::Endian<3, 100>;
+                type Bounded = crate::types::$bounded<3, 100>;
+
+                #[test]
+                fn layout() {
+                    assert_eq!(core::mem::align_of::<Endian>(), 1);
+                    assert_eq!(core::mem::size_of::<Endian>(), core::mem::size_of::<Inner>());
+                }
+
+                #[test]
+                fn get_set() {
+                    use crate::ParseErrorKind::*;
+
+                    let n: Inner = 66;
+                    let mut endian = Endian::new(n).unwrap();
+                    assert_eq!(endian.get(), 66);
+                    assert_eq!(endian.to_bytes(), n.$to_bytes());
+                    assert_eq!(endian.get_bounded(), Bounded::new(66).unwrap());
+                    assert_eq!(Endian::try_from_bytes(n.$to_bytes()).unwrap(), endian);
+
+                    endian.set(100).unwrap();
+                    assert_eq!(endian, 100);
+                    assert_eq!(endian.set(101).unwrap_err().kind(), AboveMax);
+                    assert_eq!(endian.set(2).unwrap_err().kind(), BelowMin);
+                    assert_eq!(endian, Endian::MAX);
+                    assert!(Endian::new(2).is_none());
+                    let too_big: Inner = 101;
+                    assert!(Endian::try_from_bytes(too_big.$to_bytes()).is_err());
+
+                    assert_eq!(Endian::from(Bounded::MIN), Endian::MIN);
+                    assert_eq!(Bounded::from(Endian::MIN), Bounded::MIN);
+                    assert_eq!(Inner::from(Endian::MAX), 100);
+                    assert!(Endian::MIN < Endian::MAX);
+                    assert!(Endian::new(4).unwrap() > Endian::new(3).unwrap());
+                }
+
+                #[test]
+                #[cfg(feature = "std")]
+                fn format() {
+                    use std::format;
+
+                    let endian = Endian::new(42).unwrap();
+                    assert_eq!(format!("{endian}"), "42");
+                    assert_eq!(format!("{endian:?}"), "42");
+                }
+
+                #[test]
+                #[cfg(feature = "bytemuck1")]
+                fn bytemuck() {
+                    use bytemuck1::checked::{self, CheckedCastError};
+
+                    let n: Inner = 66;
+                    let bytes = n.$to_bytes();
+                    let endian = checked::try_from_bytes::<Endian>(&bytes).unwrap();
+                    assert_eq!(endian.get(), 66);
+                    assert_eq!(bytemuck1::bytes_of(endian), bytes);
+
+                    let n: Inner = 101;
+                    assert_eq!(
+                        checked::try_from_bytes::<Endian>(&n.$to_bytes()),
+                        Err(CheckedCastError::InvalidBitPattern),
+                    );
+                }
+
+                #[test]
+                #[cfg(feature = "zerocopy08")]
+                fn zerocopy() {
+                    use zerocopy08::IntoBytes;
+
+                    let values = [Endian::MIN, Endian::new(66).unwrap()];
+                    let n: Inner = 66;
+                    assert_eq!(values[1].as_bytes(), n.$to_bytes());
+                    assert_eq!(values.as_bytes(), [Endian::MIN.to_bytes(), n.$to_bytes()].as_bytes());
+                }
+            }
+        }
+
+        pub use self::$module::Endian as $name;
+    )* };
+}
+
+define_endian_bounded_integers! {
+    BoundedU16Be BoundedU16 u16 u16_be from_be_bytes to_be_bytes "big",
+    BoundedU16Le BoundedU16 u16 u16_le from_le_bytes to_le_bytes "little",
+    BoundedU32Be BoundedU32 u32 u32_be from_be_bytes to_be_bytes "big",
+    BoundedU32Le BoundedU32 u32 u32_le from_le_bytes to_le_bytes "little",
+    BoundedU64Be BoundedU64 u64 u64_be from_be_bytes to_be_bytes "big",
+    BoundedU64Le BoundedU64 u64 u64_le from_le_bytes to_le_bytes "little",
+    BoundedU128Be BoundedU128 u128 u128_be from_be_bytes to_be_bytes "big",
+    BoundedU128Le BoundedU128 u128 u128_le from_le_bytes to_le_bytes "little",
+    BoundedI16Be BoundedI16 i16 i16_be from_be_bytes to_be_bytes "big",
+    BoundedI16Le BoundedI16 i16 i16_le from_le_bytes to_le_bytes "little",
+    BoundedI32Be BoundedI32 i32 i32_be from_be_bytes to_be_bytes "big",
+    BoundedI32Le BoundedI32 i32 i32_le from_le_bytes to_le_bytes "little",
+    BoundedI64Be BoundedI64 i64 i64_be from_be_bytes to_be_bytes "big",
+    BoundedI64Le BoundedI64 i64 i64_le from_le_bytes to_le_bytes "little",
+    BoundedI128Be BoundedI128 i128 i128_be from_be_bytes to_be_bytes "big",
+    BoundedI128Le BoundedI128 i128 i128_le from_le_bytes to_le_bytes "little",
+}
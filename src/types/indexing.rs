@@ -1,69 +1,206 @@
-//! Indexing operations on [T; N], Vec<T> and VecDeque<T> for BoundedUsize
+//! Indexing and slicing operations on [T; N], Vec<T> and VecDeque<T> for bounded integers
 
-use super::BoundedUsize;
-use core::ops::Index;
-use core::ops::IndexMut;
+/// Indexing of fixed-size arrays by bounded integers, proven in bounds at compile time.
+///
+/// Arrays can already be indexed by non-negative bounded integers with the `[]` operator, and when
+/// the maximum of the index is less than the length of the array the optimizer is able to remove
+/// the bounds check. The methods of this trait additionally guarantee that: they fail to compile
+/// unless every value of the index is in bounds, and never perform a runtime check.
+///
+/// # Examples
+///
+/// ```
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// use bounded_integer::{BoundedUsize, GetBounded};
+///
+/// let table = [1, 1, 2, 6, 24, 120, 720, 5040];
+/// let index = BoundedUsize::<0, 7>::new(5).unwrap();
+/// assert_eq!(*table.get_bounded(index), 120);
+/// ```
+///
+/// Indexing an array that is too short is a compile error:
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// use bounded_integer::{BoundedUsize, GetBounded};
+///
+/// let table = [1, 1, 2, 6, 24, 120, 720];
+/// let index = BoundedUsize::<0, 7>::new(5).unwrap();
+/// table.get_bounded(index);
+/// ```
+///
+/// Signed bounded integers can only be used as indices if they cannot be negative:
+///
+/// ```compile_fail
+#[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+/// use bounded_integer::BoundedI8;
+///
+/// let table = [1, 1, 2, 6, 24, 120, 720];
+/// let index = BoundedI8::<-1, 5>::new(5).unwrap();
+/// table[index];
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
+pub trait GetBounded<I> {
+    /// The type of the elements.
+    type Output: ?Sized;
 
-impl<const MIN: usize, const MAX: usize, T> Index<BoundedUsize<MIN, MAX>> for [T] {
-    type Output = T;
+    /// Returns a shared reference to the element at the given index.
+    #[must_use]
+    fn get_bounded(&self, index: I) -> &Self::Output;
 
-    #[inline]
-    fn index(&self, index: BoundedUsize<MIN, MAX>) -> &Self::Output {
-        &self[index.get()]
-    }
+    /// Returns a mutable reference to the element at the given index.
+    #[must_use]
+    fn get_bounded_mut(&mut self, index: I) -> &mut Self::Output;
 }
 
-impl<const MIN: usize, const MAX: usize, T> IndexMut<BoundedUsize<MIN, MAX>> for [T] {
-    #[inline]
-    fn index_mut(&mut self, index: BoundedUsize<MIN, MAX>) -> &mut Self::Output {
-        &mut self[index.get()]
-    }
-}
+macro_rules! impl_index {
+    ($($name:ident $inner:ident,)*) => { $( mod $inner {
+        use core::ops::Index;
+        use core::ops::IndexMut;
 
-#[cfg(feature = "alloc")]
-impl<const MIN: usize, const MAX: usize, T> Index<BoundedUsize<MIN, MAX>> for alloc::vec::Vec<T> {
-    type Output = T;
+        use super::GetBounded;
+        use crate::types::$name as Bounded;
 
-    #[inline]
-    fn index(&self, index: BoundedUsize<MIN, MAX>) -> &Self::Output {
-        &self[index.get()]
-    }
-}
+        type Inner = core::primitive::$inner;
 
-#[cfg(feature = "alloc")]
-impl<const MIN: usize, const MAX: usize, T> IndexMut<BoundedUsize<MIN, MAX>>
-    for alloc::vec::Vec<T>
-{
-    #[inline]
-    fn index_mut(&mut self, index: BoundedUsize<MIN, MAX>) -> &mut Self::Output {
-        &mut self[index.get()]
-    }
-}
+        struct NonNegative<const MIN: Inner>;
 
-#[cfg(feature = "alloc")]
-impl<const MIN: usize, const MAX: usize, T> Index<BoundedUsize<MIN, MAX>>
-    for alloc::collections::VecDeque<T>
-{
-    type Output = T;
+        impl<const MIN: Inner> NonNegative<MIN> {
+            #[allow(unused_comparisons)]
+            const OK: () = assert!(MIN >= 0, "bounded integers used as indices must not be negative");
+        }
 
-    #[inline]
-    fn index(&self, index: BoundedUsize<MIN, MAX>) -> &Self::Output {
-        &self[index.get()]
-    }
+        struct InBounds<const MAX: Inner, const N: usize>;
+
+        impl<const MAX: Inner, const N: usize> InBounds<MAX, N> {
+            const OK: () = assert!(
+                (MAX as u128) < N as u128,
+                "maximum of index is out of bounds of the array",
+            );
+        }
+
+        /// Converts the index to a `usize`, telling the optimizer that it is in the range
+        /// `MIN..=MAX` so that bounds checks against a long enough collection can be elided.
+        #[inline(always)]
+        fn hint<const MIN: Inner, const MAX: Inner>(index: Bounded<MIN, MAX>) -> usize {
+            let () = NonNegative::<MIN>::OK;
+            let index = index.get();
+            if !Bounded::<MIN, MAX>::in_range(index) {
+                // SAFETY: Bounded integers are never outside their range.
+                unsafe { core::hint::unreachable_unchecked() }
+            }
+            // An index that does not fit in a `usize` is out of bounds of any collection, as is
+            // `usize::MAX`.
+            usize::try_from(index).unwrap_or(usize::MAX)
+        }
+
+        impl<const MIN: Inner, const MAX: Inner, T> Index<Bounded<MIN, MAX>> for [T] {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: Bounded<MIN, MAX>) -> &Self::Output {
+                &self[hint(index)]
+            }
+        }
+
+        impl<const MIN: Inner, const MAX: Inner, T> IndexMut<Bounded<MIN, MAX>> for [T] {
+            #[inline]
+            fn index_mut(&mut self, index: Bounded<MIN, MAX>) -> &mut Self::Output {
+                &mut self[hint(index)]
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<const MIN: Inner, const MAX: Inner, T> Index<Bounded<MIN, MAX>>
+            for alloc::vec::Vec<T>
+        {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: Bounded<MIN, MAX>) -> &Self::Output {
+                &self[hint(index)]
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<const MIN: Inner, const MAX: Inner, T> IndexMut<Bounded<MIN, MAX>>
+            for alloc::vec::Vec<T>
+        {
+            #[inline]
+            fn index_mut(&mut self, index: Bounded<MIN, MAX>) -> &mut Self::Output {
+                &mut self[hint(index)]
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<const MIN: Inner, const MAX: Inner, T> Index<Bounded<MIN, MAX>>
+            for alloc::collections::VecDeque<T>
+        {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: Bounded<MIN, MAX>) -> &Self::Output {
+                &self[hint(index)]
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<const MIN: Inner, const MAX: Inner, T> IndexMut<Bounded<MIN, MAX>>
+            for alloc::collections::VecDeque<T>
+        {
+            #[inline]
+            fn index_mut(&mut self, index: Bounded<MIN, MAX>) -> &mut Self::Output {
+                &mut self[hint(index)]
+            }
+        }
+
+        impl<const MIN: Inner, const MAX: Inner> crate::slice::SliceIndex for Bounded<MIN, MAX> {
+            #[inline]
+            fn to_slice_index(self) -> usize {
+                hint(self)
+            }
+        }
+
+        impl<const MIN: Inner, const MAX: Inner, T, const N: usize> GetBounded<Bounded<MIN, MAX>>
+            for [T; N]
+        {
+            type Output = T;
+
+            #[inline]
+            fn get_bounded(&self, index: Bounded<MIN, MAX>) -> &Self::Output {
+                let () = InBounds::<MAX, N>::OK;
+                // SAFETY: `0 <= index <= MAX < N`.
+                unsafe { self.get_unchecked(hint(index)) }
+            }
+
+            #[inline]
+            fn get_bounded_mut(&mut self, index: Bounded<MIN, MAX>) -> &mut Self::Output {
+                let () = InBounds::<MAX, N>::OK;
+                // SAFETY: `0 <= index <= MAX < N`.
+                unsafe { self.get_unchecked_mut(hint(index)) }
+            }
+        }
+    } )* }
 }
 
-#[cfg(feature = "alloc")]
-impl<const MIN: usize, const MAX: usize, T> IndexMut<BoundedUsize<MIN, MAX>>
-    for alloc::collections::VecDeque<T>
-{
-    #[inline]
-    fn index_mut(&mut self, index: BoundedUsize<MIN, MAX>) -> &mut Self::Output {
-        &mut self[index.get()]
-    }
+impl_index! {
+    BoundedU8 u8,
+    BoundedU16 u16,
+    BoundedU32 u32,
+    BoundedU64 u64,
+    BoundedU128 u128,
+    BoundedUsize usize,
+    BoundedI8 i8,
+    BoundedI16 i16,
+    BoundedI32 i32,
+    BoundedI64 i64,
+    BoundedI128 i128,
+    BoundedIsize isize,
 }
 
 #[cfg(test)]
 mod tests {
+    use super::GetBounded;
     use crate::types::BoundedUsize;
 
     #[test]
@@ -94,6 +231,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn get_bounded() {
+        let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        for i in 0..arr.len() {
+            let b_u = BoundedUsize::<0, 9>::new(i).unwrap();
+            assert_eq!(*arr.get_bounded(b_u), i);
+
+            *arr.get_bounded_mut(b_u) += 5;
+            assert_eq!(*arr.get_bounded(b_u), i + 5);
+        }
+
+        let b_u = BoundedUsize::<3, 5>::new(4).unwrap();
+        assert_eq!(*[0; 6].get_bounded(b_u), 0);
+    }
+
+    #[test]
+    fn indexing_widths() {
+        use crate::types::{BoundedI16, BoundedI8, BoundedU128, BoundedU16, BoundedU8};
+
+        let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        for i in 0..arr.len() {
+            let b_u8 = BoundedU8::<0, 30>::new(i as u8).unwrap();
+            let b_u16 = BoundedU16::<0, 30>::new(i as u16).unwrap();
+            let b_u128 = BoundedU128::<0, 30>::new(i as u128).unwrap();
+            let b_i8 = BoundedI8::<0, 30>::new(i as i8).unwrap();
+
+            assert_eq!(arr[b_u8], i);
+            assert_eq!(arr[b_u16], i);
+            assert_eq!(arr[b_u128], i);
+            assert_eq!(arr[b_i8], i);
+
+            arr[b_u8] += 1;
+            assert_eq!(arr[b_u16], i + 1);
+        }
+
+        let b_i16 = BoundedI16::<2, 9>::new(5).unwrap();
+        assert_eq!(*arr.get_bounded(b_i16), 6);
+    }
+
+    #[test]
+    #[should_panic = "index out of bounds"]
+    fn indexing_out_of_bounds() {
+        let arr = [0; 10];
+        let _ = arr[crate::types::BoundedU64::<0, { u64::MAX }>::MAX];
+    }
+
+    #[test]
+    fn slicing() {
+        use crate::types::BoundedU8;
+        use crate::SliceBounded;
+
+        let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let a = BoundedU8::<0, 10>::new(2).unwrap();
+        let b = BoundedU8::<0, 10>::new(5).unwrap();
+
+        assert_eq!(arr.slice_bounded(a..b), [2, 3, 4]);
+        assert_eq!(arr.slice_bounded(a..=b), [2, 3, 4, 5]);
+        assert_eq!(arr.slice_bounded(b..), [5, 6, 7, 8, 9]);
+        assert_eq!(arr.slice_bounded(..a), [0, 1]);
+        assert_eq!(arr.slice_bounded(..=a), [0, 1, 2]);
+        assert_eq!(arr.slice_bounded(BoundedU8::<0, 10>::MAX..), [0; 0]);
+
+        arr.slice_bounded_mut(a..b).fill(0);
+        assert_eq!(arr, [0, 1, 0, 0, 0, 5, 6, 7, 8, 9]);
+
+        let mut s = *b"bounded";
+        let s = core::str::from_utf8_mut(&mut s).unwrap();
+        assert_eq!(s.slice_bounded(a..b), "und");
+        s.slice_bounded_mut(..=a).make_ascii_uppercase();
+        assert_eq!(s, "BOUnded");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn slicing_alloc() {
+        use crate::SliceBounded;
+
+        let vec = (0..20).collect::<alloc::vec::Vec<usize>>();
+        let string = alloc::string::String::from("bounded");
+        let a = BoundedUsize::<0, 30>::new(3).unwrap();
+
+        assert_eq!(vec.slice_bounded(..a), [0, 1, 2]);
+        assert_eq!(string.slice_bounded(a..), "nded");
+    }
+
+    #[test]
+    #[should_panic = "out of range"]
+    fn slicing_out_of_bounds() {
+        use crate::SliceBounded;
+
+        let arr = [0; 10];
+        let _ = arr.slice_bounded(..crate::types::BoundedU64::<0, { u64::MAX }>::MAX);
+    }
+
     #[test]
     fn indexing_mut() {
         let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
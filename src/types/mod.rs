@@ -155,6 +155,16 @@ macro_rules! impl_fmt_traits {
     )* }
 }
 
+macro_rules! zerocopy08_unaligned {
+    (u8 $item:item) => { zerocopy08_unaligned!(@derive $item); };
+    (i8 $item:item) => { zerocopy08_unaligned!(@derive $item); };
+    ($inner:ident $item:item) => { $item };
+    (@derive $item:item) => {
+        #[cfg_attr(feature = "zerocopy08", derive(zerocopy08::Unaligned))]
+        $item
+    };
+}
+
 macro_rules! define_bounded_integers {
     ($(
         $name:ident $inner:ident $(signed $([$signed:ident])?)? -> $($into:ident)*,
@@ -163,20 +173,27 @@ macro_rules! define_bounded_integers {
         use core::cmp;
         use core::fmt;
         use core::iter;
+        use core::mem;
         use core::str::FromStr;
 
         use crate::parse::{ParseError, FromStrRadix};
 
         type Inner = core::primitive::$inner;
 
-        #[doc = "An"]
-        #[doc = concat!("[`", stringify!($inner), "`]")]
-        #[doc = "constrained to be in the range `MIN..=MAX`."]
-        #[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
-        #[repr(transparent)]
-        #[derive(Debug, Hash, Clone, Copy, Eq, Ord)]
-        #[cfg_attr(zerocopy06, derive(zerocopy06::AsBytes))]
-        pub struct Bounded<const MIN: Inner, const MAX: Inner>(Inner);
+        zerocopy08_unaligned! { $inner
+            #[doc = "An"]
+            #[doc = concat!("[`", stringify!($inner), "`]")]
+            #[doc = "constrained to be in the range `MIN..=MAX`."]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "types")))]
+            #[repr(transparent)]
+            #[derive(Debug, Hash, Clone, Copy, Eq, Ord)]
+            #[cfg_attr(
+                feature = "zerocopy08",
+                derive(zerocopy08::IntoBytes, zerocopy08::KnownLayout, zerocopy08::Immutable),
+                zerocopy(crate = "zerocopy08"),
+            )]
+            pub struct Bounded<const MIN: Inner, const MAX: Inner>(Inner);
+        }
 
         impl<const MIN: Inner, const MAX: Inner> Bounded<MIN, MAX> {
             /// The smallest value this bounded integer can contain.
@@ -184,6 +201,13 @@ macro_rules! define_bounded_integers {
             /// The largest value that this bounded integer can contain.
             pub const MAX_VALUE: Inner = MAX;
 
+            /// The number of decimal digits of the longest value of the bounded integer, not
+            /// including the sign.
+            pub const MAX_DIGITS: usize = Self::max_digits(10);
+            /// The length of the longest decimal representation of a value of the bounded integer,
+            /// including the sign.
+            pub const MAX_LEN: usize = crate::format::max_len(sign_magnitude(MIN), sign_magnitude(MAX));
+
             /// The smallest value of the bounded integer.
             pub const MIN: Self = Self(MIN);
             /// The largest value of the bounded integer.
@@ -270,6 +294,107 @@ macro_rules! define_bounded_integers {
                 })
             }
 
+            /// Creates a slice of bounded integers from a slice of primitives if all the values are
+            /// within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of
+            /// the first value that isn't.
+            #[inline]
+            pub fn new_slice(n: &[Inner]) -> Result<&[Self], usize> {
+                match n.iter().position(|&n| !Self::in_range(n)) {
+                    Some(index) => Err(index),
+                    // SAFETY: We just checked that all the values are in range.
+                    None => Ok(unsafe { Self::new_slice_unchecked(n) }),
+                }
+            }
+
+            /// Creates a mutable slice of bounded integers from a mutable slice of primitives if
+            /// all the values are within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or
+            /// returns the index of the first value that isn't.
+            #[inline]
+            pub fn new_slice_mut(n: &mut [Inner]) -> Result<&mut [Self], usize> {
+                match n.iter().position(|&n| !Self::in_range(n)) {
+                    Some(index) => Err(index),
+                    // SAFETY: We just checked that all the values are in range.
+                    None => Ok(unsafe { Self::new_slice_mut_unchecked(n) }),
+                }
+            }
+
+            /// Creates a slice of bounded integers from a slice of primitives without checking the
+            /// values.
+            ///
+            /// # Safety
+            ///
+            /// No value may be outside the valid range of values; they must not be less than
+            /// [`MIN_VALUE`](Self::MIN_VALUE) or greater than [`MAX_VALUE`](Self::MAX_VALUE).
+            #[must_use]
+            pub unsafe fn new_slice_unchecked(n: &[Inner]) -> &[Self] {
+                debug_assert!(n.iter().all(|&n| Self::in_range(n)));
+                &*(n as *const [Inner] as *const [Self])
+            }
+
+            /// Creates a mutable slice of bounded integers from a mutable slice of primitives
+            /// without checking the values.
+            ///
+            /// # Safety
+            ///
+            /// No value may be outside the valid range of values; they must not be less than
+            /// [`MIN_VALUE`](Self::MIN_VALUE) or greater than [`MAX_VALUE`](Self::MAX_VALUE).
+            #[must_use]
+            pub unsafe fn new_slice_mut_unchecked(n: &mut [Inner]) -> &mut [Self] {
+                debug_assert!(n.iter().all(|&n| Self::in_range(n)));
+                &mut *(n as *mut [Inner] as *mut [Self])
+            }
+
+            /// Creates an array of bounded integers from an array of primitives if all the values
+            /// are within the range [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the
+            /// index of the first value that isn't.
+            #[inline]
+            pub fn new_array<const N: usize>(n: [Inner; N]) -> Result<[Self; N], usize> {
+                Self::new_array_ref(&n)?;
+                // SAFETY: We just checked that all the values are in range.
+                Ok(n.map(|n| unsafe { Self::new_unchecked(n) }))
+            }
+
+            /// Creates a reference to an array of bounded integers from a reference to an array of
+            /// primitives if all the values are within the range
+            /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of the first value
+            /// that isn't.
+            #[inline]
+            pub fn new_array_ref<const N: usize>(n: &[Inner; N]) -> Result<&[Self; N], usize> {
+                let slice = Self::new_slice(n)?;
+                // SAFETY: The slice has the same length as the array.
+                Ok(unsafe { &*slice.as_ptr().cast::<[Self; N]>() })
+            }
+
+            /// Creates a mutable reference to an array of bounded integers from a mutable
+            /// reference to an array of primitives if all the values are within the range
+            /// [[`MIN`](Self::MIN), [`MAX`](Self::MAX)], or returns the index of the first value
+            /// that isn't.
+            #[inline]
+            pub fn new_array_mut<const N: usize>(
+                n: &mut [Inner; N],
+            ) -> Result<&mut [Self; N], usize> {
+                let slice = Self::new_slice_mut(n)?;
+                // SAFETY: The slice has the same length as the array.
+                Ok(unsafe { &mut *slice.as_mut_ptr().cast::<[Self; N]>() })
+            }
+
+            /// Converts a slice of bounded integers to a slice of primitives.
+            #[must_use]
+            #[inline]
+            pub const fn as_inner_slice(slice: &[Self]) -> &[Inner] {
+                // SAFETY: The bounded integer is a transparent wrapper around the primitive.
+                unsafe { &*(slice as *const [Self] as *const [Inner]) }
+            }
+
+            /// Converts a reference to an array of bounded integers to a reference to an array of
+            /// primitives.
+            #[must_use]
+            #[inline]
+            pub const fn as_inner_array<const N: usize>(array: &[Self; N]) -> &[Inner; N] {
+                // SAFETY: The bounded integer is a transparent wrapper around the primitive.
+                unsafe { &*(array as *const [Self; N] as *const [Inner; N]) }
+            }
+
             /// Creates a bounded integer by setting the value to [`MIN`](Self::MIN) or
             /// [`MAX`](Self::MAX) if it is too low or too high respectively.
             #[must_use]
@@ -291,15 +416,160 @@ macro_rules! define_bounded_integers {
             /// Panics if `radix` is below 2 or above 36.
             pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
                 let value = <Inner as FromStrRadix>::from_str_radix(src, radix)?;
-                if value < Self::MIN_VALUE {
+                Self::new_or_error(value)
+            }
+
+            /// Creates a bounded integer, or returns an error of kind
+            /// [`BelowMin`](crate::ParseErrorKind::BelowMin) or
+            /// [`AboveMax`](crate::ParseErrorKind::AboveMax) if the value is out of range.
+            const fn new_or_error(n: Inner) -> Result<Self, ParseError> {
+                if n < Self::MIN_VALUE {
                     Err(crate::parse::error_below_min())
-                } else if value > Self::MAX_VALUE {
+                } else if n > Self::MAX_VALUE {
                     Err(crate::parse::error_above_max())
                 } else {
-                    Ok(unsafe { Self::new_unchecked(value) })
+                    Ok(Self(n))
                 }
             }
 
+            /// Returns the memory representation of the bounded integer as a byte array in
+            /// little-endian byte order.
+            #[must_use]
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; mem::size_of::<Inner>()] {
+                self.get().to_le_bytes()
+            }
+
+            /// Returns the memory representation of the bounded integer as a byte array in
+            /// big-endian byte order.
+            #[must_use]
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; mem::size_of::<Inner>()] {
+                self.get().to_be_bytes()
+            }
+
+            /// Returns the memory representation of the bounded integer as a byte array in native
+            /// byte order.
+            #[must_use]
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; mem::size_of::<Inner>()] {
+                self.get().to_ne_bytes()
+            }
+
+            /// Creates a bounded integer from its representation as a byte array in little-endian
+            /// byte order.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error of kind [`BelowMin`](crate::ParseErrorKind::BelowMin) or
+            /// [`AboveMax`](crate::ParseErrorKind::AboveMax) if the value is out of range.
+            #[inline]
+            pub const fn try_from_le_bytes(
+                bytes: [u8; mem::size_of::<Inner>()],
+            ) -> Result<Self, ParseError> {
+                Self::new_or_error(Inner::from_le_bytes(bytes))
+            }
+
+            /// Creates a bounded integer from its representation as a byte array in big-endian
+            /// byte order.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error of kind [`BelowMin`](crate::ParseErrorKind::BelowMin) or
+            /// [`AboveMax`](crate::ParseErrorKind::AboveMax) if the value is out of range.
+            #[inline]
+            pub const fn try_from_be_bytes(
+                bytes: [u8; mem::size_of::<Inner>()],
+            ) -> Result<Self, ParseError> {
+                Self::new_or_error(Inner::from_be_bytes(bytes))
+            }
+
+            /// Creates a bounded integer from its representation as a byte array in native byte
+            /// order.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error of kind [`BelowMin`](crate::ParseErrorKind::BelowMin) or
+            /// [`AboveMax`](crate::ParseErrorKind::AboveMax) if the value is out of range.
+            #[inline]
+            pub const fn try_from_ne_bytes(
+                bytes: [u8; mem::size_of::<Inner>()],
+            ) -> Result<Self, ParseError> {
+                Self::new_or_error(Inner::from_ne_bytes(bytes))
+            }
+
+            /// Returns the number of digits of the longest value of the bounded integer in a given
+            /// base, not including the sign.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is below 2 or above 36.
+            #[must_use]
+            pub const fn max_digits(radix: u32) -> usize {
+                crate::format::max_digits(sign_magnitude(MIN).1, sign_magnitude(MAX).1, radix)
+            }
+
+            /// Returns an object that displays the bounded integer in decimal, padded with leading
+            /// zeros to [`MAX_DIGITS`](Self::MAX_DIGITS) digits.
+            ///
+            /// The sign, if any, is written before the zeros and does not count towards the
+            /// digits.
+            #[must_use]
+            pub fn display_padded(self) -> impl fmt::Display {
+                let (negative, magnitude) = sign_magnitude(self.0);
+                crate::format::Formatted::new(negative, magnitude, 10, Self::MAX_DIGITS)
+            }
+
+            /// Returns an object that displays the bounded integer in a given base, using
+            /// lowercase letters for digits above 9.
+            ///
+            /// This is the inverse of [`from_str_radix`](Self::from_str_radix).
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is below 2 or above 36.
+            #[must_use]
+            pub fn format_radix(self, radix: u32) -> impl fmt::Display {
+                let (negative, magnitude) = sign_magnitude(self.0);
+                crate::format::Formatted::new(negative, magnitude, radix, 0)
+            }
+
+            /// Writes the bounded integer in decimal to the end of a buffer, returning the written
+            /// part as a string.
+            ///
+            /// This is much faster than formatting with [`Display`](fmt::Display) and does not
+            /// require a [`fmt::Write`]. The buffer must be at least [`MAX_LEN`](Self::MAX_LEN)
+            /// bytes long, which is checked at compile time.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+            #[doc = concat!("# use bounded_integer::", stringify!($name), ";")]
+            #[doc = concat!("type Bounded = ", stringify!($name), "<0, 100>;")]
+            ///
+            /// let mut buf = [0; Bounded::MAX_LEN];
+            /// assert_eq!(Bounded::new(42).unwrap().to_ascii(&mut buf), "42");
+            /// ```
+            ///
+            /// A buffer that is too short fails to compile:
+            ///
+            /// ```compile_fail
+            #[cfg_attr(feature = "step_trait", doc = "# #![feature(step_trait)]")]
+            #[doc = concat!("# use bounded_integer::", stringify!($name), ";")]
+            #[doc = concat!("type Bounded = ", stringify!($name), "<0, 100>;")]
+            ///
+            /// let mut buf = [0; 2];
+            /// Bounded::new(42).unwrap().to_ascii(&mut buf);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn to_ascii<const N: usize>(self, buf: &mut [u8; N]) -> &str {
+                let () = AsciiBuffer::<MIN, MAX, N>::OK;
+                let (negative, magnitude) = sign_magnitude(self.0);
+                crate::format::to_ascii(negative, magnitude, buf)
+            }
+
             /// Returns the value of the bounded integer as a primitive type.
             #[must_use]
             #[inline]
@@ -717,6 +987,62 @@ macro_rules! define_bounded_integers {
 
         impl_fmt_traits!(Binary, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex);
 
+        struct AsciiBuffer<const MIN: Inner, const MAX: Inner, const N: usize>;
+
+        impl<const MIN: Inner, const MAX: Inner, const N: usize> AsciiBuffer<MIN, MAX, N> {
+            const OK: () = assert!(
+                N >= Bounded::<MIN, MAX>::MAX_LEN,
+                "buffer must be at least `MAX_LEN` bytes long",
+            );
+        }
+
+        /// Splits an integer into whether it is negative and its absolute value.
+        #[allow(unused_comparisons)]
+        const fn sign_magnitude(n: Inner) -> (bool, u128) {
+            if n < 0 {
+                (true, (n as i128).unsigned_abs())
+            } else {
+                (false, n as u128)
+            }
+        }
+
+        // === Key ===
+
+        unsafe impl<const MIN: Inner, const MAX: Inner> crate::BoundedKey for Bounded<MIN, MAX> {
+            const COUNT: usize = crate::key::count(if MIN <= MAX {
+                Some((MAX as i128).wrapping_sub(MIN as i128) as u128)
+            } else {
+                None
+            });
+
+            #[inline]
+            fn to_index(self) -> usize {
+                (self.get() as i128).wrapping_sub(MIN as i128) as usize
+            }
+
+            #[inline]
+            unsafe fn from_index_unchecked(index: usize) -> Self {
+                Self::new_unchecked((MIN as i128).wrapping_add(index as i128) as Inner)
+            }
+        }
+
+        impl<const MIN: Inner, const MAX: Inner> crate::BoundedBits for Bounded<MIN, MAX> {
+            const MAX_OFFSET: u128 = (MAX as i128).wrapping_sub(MIN as i128) as u128;
+
+            #[inline]
+            fn to_offset_bits(self) -> u128 {
+                (self.get() as i128).wrapping_sub(MIN as i128) as u128
+            }
+
+            #[inline]
+            fn from_offset_bits(bits: u128) -> Option<Self> {
+                // SAFETY: We just checked that the offset is in range.
+                (MIN <= MAX && bits <= Self::MAX_OFFSET).then(|| unsafe {
+                    Self::new_unchecked((MIN as i128).wrapping_add(bits as i128) as Inner)
+                })
+            }
+        }
+
         // === Arbitrary ===
 
         #[cfg(feature = "arbitrary1")]
@@ -735,6 +1061,41 @@ macro_rules! define_bounded_integers {
             }
         }
 
+        // === Borsh ===
+
+        #[cfg(feature = "borsh1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "borsh1")))]
+        impl<const MIN: Inner, const MAX: Inner> borsh1::BorshSerialize for Bounded<MIN, MAX> {
+            fn serialize<W: borsh1::io::Write>(&self, writer: &mut W) -> borsh1::io::Result<()> {
+                borsh1::BorshSerialize::serialize(&self.get(), writer)
+            }
+        }
+
+        #[cfg(feature = "borsh1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "borsh1")))]
+        impl<const MIN: Inner, const MAX: Inner> borsh1::BorshDeserialize for Bounded<MIN, MAX> {
+            fn deserialize_reader<R: borsh1::io::Read>(reader: &mut R) -> borsh1::io::Result<Self> {
+                Self::new(borsh1::BorshDeserialize::deserialize_reader(reader)?).ok_or_else(crate::borsh::out_of_range)
+            }
+        }
+
+        #[cfg(feature = "borsh1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "borsh1")))]
+        impl<const MIN: Inner, const MAX: Inner> borsh1::BorshSchema for Bounded<MIN, MAX> {
+            fn add_definitions_recursively(
+                definitions: &mut alloc::collections::BTreeMap<
+                    borsh1::schema::Declaration,
+                    borsh1::schema::Definition,
+                >,
+            ) {
+                crate::borsh::add_definitions::<Inner>(Self::declaration(), definitions);
+            }
+
+            fn declaration() -> borsh1::schema::Declaration {
+                crate::borsh::declaration(stringify!($name), MIN, MAX)
+            }
+        }
+
         // === Bytemuck ===
 
         #[cfg(feature = "bytemuck1")]
@@ -745,6 +1106,78 @@ macro_rules! define_bounded_integers {
             const MIN_VALUE: Inner = MIN;
         }
 
+        #[cfg(feature = "bytemuck1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "bytemuck1")))]
+        unsafe impl<const MIN: Inner, const MAX: Inner> bytemuck1::NoUninit for Bounded<MIN, MAX> {}
+
+        #[cfg(feature = "bytemuck1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "bytemuck1")))]
+        unsafe impl<const MIN: Inner, const MAX: Inner> bytemuck1::CheckedBitPattern
+            for Bounded<MIN, MAX>
+        {
+            type Bits = Inner;
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &Inner) -> bool {
+                Self::in_range(*bits)
+            }
+        }
+
+        // === Clap ===
+
+        #[cfg(feature = "clap4")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "clap4")))]
+        impl<const MIN: Inner, const MAX: Inner> clap4::builder::ValueParserFactory
+            for Bounded<MIN, MAX>
+        {
+            type Parser = crate::BoundedValueParser<Self>;
+
+            fn value_parser() -> Self::Parser {
+                crate::BoundedValueParser::new()
+            }
+        }
+
+        #[cfg(feature = "clap4")]
+        impl<const MIN: Inner, const MAX: Inner> crate::clap::Parse for Bounded<MIN, MAX> {
+            type Inner = Inner;
+            const MIN: Inner = MIN;
+            const MAX: Inner = MAX;
+        }
+
+        // === Defmt ===
+
+        #[cfg(feature = "defmt1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "defmt1")))]
+        impl<const MIN: Inner, const MAX: Inner> defmt1::Format for Bounded<MIN, MAX> {
+            fn format(&self, fmt: defmt1::Formatter<'_>) {
+                defmt1::Format::format(&self.get(), fmt);
+            }
+        }
+
+        // === Ufmt ===
+
+        #[cfg(feature = "ufmt02")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "ufmt02")))]
+        impl<const MIN: Inner, const MAX: Inner> ufmt02::uDisplay for Bounded<MIN, MAX> {
+            fn fmt<W>(&self, f: &mut ufmt02::Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: ufmt02::uWrite + ?Sized,
+            {
+                ufmt02::uDisplay::fmt(&self.get(), f)
+            }
+        }
+
+        #[cfg(feature = "ufmt02")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "ufmt02")))]
+        impl<const MIN: Inner, const MAX: Inner> ufmt02::uDebug for Bounded<MIN, MAX> {
+            fn fmt<W>(&self, f: &mut ufmt02::Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: ufmt02::uWrite + ?Sized,
+            {
+                ufmt02::uDebug::fmt(&self.get(), f)
+            }
+        }
+
         // === Num ===
 
         #[cfg(feature = "num-traits02")]
@@ -1039,6 +1472,104 @@ macro_rules! define_bounded_integers {
             }
         }
 
+        // === PyO3 ===
+
+        #[cfg(feature = "pyo3028")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "pyo3028")))]
+        impl<'py, const MIN: Inner, const MAX: Inner> pyo3028::FromPyObject<'_, 'py>
+            for Bounded<MIN, MAX>
+        {
+            type Error = pyo3028::PyErr;
+
+            fn extract(obj: pyo3028::Borrowed<'_, 'py, pyo3028::PyAny>) -> pyo3028::PyResult<Self> {
+                crate::pyo3::extract(obj, Self::new, MIN, MAX)
+            }
+        }
+
+        #[cfg(feature = "pyo3028")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "pyo3028")))]
+        impl<'py, const MIN: Inner, const MAX: Inner> pyo3028::IntoPyObject<'py>
+            for Bounded<MIN, MAX>
+        {
+            type Target = pyo3028::types::PyInt;
+            type Output = pyo3028::Bound<'py, pyo3028::types::PyInt>;
+            type Error = core::convert::Infallible;
+
+            fn into_pyobject(self, py: pyo3028::Python<'py>) -> Result<Self::Output, Self::Error> {
+                self.get().into_pyobject(py)
+            }
+        }
+
+        #[cfg(feature = "pyo3028")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "pyo3028")))]
+        impl<'py, const MIN: Inner, const MAX: Inner> pyo3028::IntoPyObject<'py>
+            for &Bounded<MIN, MAX>
+        {
+            type Target = pyo3028::types::PyInt;
+            type Output = pyo3028::Bound<'py, pyo3028::types::PyInt>;
+            type Error = core::convert::Infallible;
+
+            fn into_pyobject(self, py: pyo3028::Python<'py>) -> Result<Self::Output, Self::Error> {
+                self.get().into_pyobject(py)
+            }
+        }
+
+        // === Rusqlite ===
+
+        #[cfg(feature = "rusqlite040")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "rusqlite040")))]
+        impl<const MIN: Inner, const MAX: Inner> rusqlite040::ToSql for Bounded<MIN, MAX> {
+            fn to_sql(&self) -> rusqlite040::Result<rusqlite040::types::ToSqlOutput<'_>> {
+                crate::rusqlite::to_sql(self.get())
+            }
+        }
+
+        #[cfg(feature = "rusqlite040")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "rusqlite040")))]
+        impl<const MIN: Inner, const MAX: Inner> rusqlite040::types::FromSql for Bounded<MIN, MAX> {
+            fn column_result(
+                value: rusqlite040::types::ValueRef<'_>,
+            ) -> rusqlite040::types::FromSqlResult<Self> {
+                crate::rusqlite::from_sql(value, Self::new)
+            }
+        }
+
+        // === Schemars ===
+
+        #[cfg(feature = "schemars1")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "schemars1")))]
+        impl<const MIN: Inner, const MAX: Inner> schemars1::JsonSchema for Bounded<MIN, MAX> {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> alloc::borrow::Cow<'static, str> {
+                crate::schemars::name(stringify!($name), MIN, MAX)
+            }
+
+            fn json_schema(generator: &mut schemars1::SchemaGenerator) -> schemars1::Schema {
+                crate::schemars::schema(generator, MIN, MAX)
+            }
+        }
+
+        // === Utoipa ===
+
+        #[cfg(feature = "utoipa5")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "utoipa5")))]
+        impl<const MIN: Inner, const MAX: Inner> utoipa5::PartialSchema for Bounded<MIN, MAX> {
+            fn schema() -> utoipa5::openapi::RefOr<utoipa5::openapi::schema::Schema> {
+                crate::utoipa::schema(MIN, MAX)
+            }
+        }
+
+        #[cfg(feature = "utoipa5")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "utoipa5")))]
+        impl<const MIN: Inner, const MAX: Inner> utoipa5::ToSchema for Bounded<MIN, MAX> {
+            fn name() -> alloc::borrow::Cow<'static, str> {
+                crate::utoipa::name(stringify!($name), MIN, MAX)
+            }
+        }
+
         // === Serde ===
 
         #[cfg(feature = "serde1")]
@@ -1067,6 +1598,23 @@ macro_rules! define_bounded_integers {
             }
         }
 
+        #[cfg(feature = "serde1")]
+        impl<const MIN: Inner, const MAX: Inner> crate::serde::Lenient for Bounded<MIN, MAX> {
+            const SIGNED: bool = Inner::MIN != 0;
+            const INNER_BITS: u32 = Inner::BITS;
+            const MIN_BITS: u128 = MIN as u128;
+        }
+
+        // === Zerocopy ===
+
+        // SAFETY: `Bounded` is a `repr(transparent)` wrapper of `Inner`, which is `AsBytes`. This
+        // can't be derived because zerocopy-derive 0.6 doesn't support const generic parameters.
+        #[cfg(feature = "zerocopy06")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "zerocopy06")))]
+        unsafe impl<const MIN: Inner, const MAX: Inner> zerocopy06::AsBytes for Bounded<MIN, MAX> {
+            fn only_derive_is_allowed_to_implement_this_trait() {}
+        }
+
         // === Conversions ===
 
         $(impl<const MIN: Inner, const MAX: Inner> From<Bounded<MIN, MAX>> for $into {
@@ -1109,6 +1657,94 @@ macro_rules! define_bounded_integers {
                 assert_eq!(Bounded::new_saturating(2).get(), 3);
             }
 
+            #[test]
+            fn bits() {
+                use crate::{BitReader, BitWriter, BoundedBits};
+
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::BITS, 3);
+                assert_eq!(Bounded::MIN.to_offset_bits(), 0);
+                assert_eq!(Bounded::MAX.to_offset_bits(), 7);
+                assert_eq!(Bounded::from_offset_bits(5).unwrap().get(), 8);
+                assert_eq!(Bounded::from_offset_bits(8), None);
+
+                assert_eq!(super::Bounded::<3, 3>::BITS, 0);
+                assert_eq!(super::Bounded::<3, 11>::BITS, 4);
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::BITS, Inner::BITS);
+                assert_eq!(Full::MAX.to_offset_bits(), Inner::MAX.abs_diff(Inner::MIN) as u128);
+                assert_eq!(Full::from_offset_bits(0).unwrap(), Full::MIN);
+                type Empty = super::Bounded<4, 3>;
+                assert_eq!(Empty::from_offset_bits(0), None);
+
+                let mut buf = [0; 2 + Inner::BITS as usize / 8];
+                let mut writer = BitWriter::new(&mut buf);
+                writer.write(Bounded::new(9).unwrap()).unwrap();
+                writer.write(Full::MAX).unwrap();
+                writer.write(Bounded::MAX).unwrap();
+                writer.write(super::Bounded::<3, 3>::MIN).unwrap();
+                assert_eq!(writer.bit_position(), 6 + Inner::BITS as usize);
+
+                let mut reader = BitReader::new(&buf);
+                assert_eq!(reader.read::<Bounded>().unwrap(), 9);
+                assert_eq!(reader.read::<Full>().unwrap(), Full::MAX);
+                assert_eq!(reader.read::<Bounded>().unwrap(), 10);
+                assert_eq!(reader.read::<super::Bounded<3, 3>>().unwrap(), 3);
+                assert_eq!(reader.bit_position(), 6 + Inner::BITS as usize);
+
+                let mut reader = BitReader::new(&[0xFF]);
+                assert_eq!(reader.read::<super::Bounded<3, 5>>(), None);
+                assert_eq!(reader.bit_position(), 0);
+            }
+
+            #[test]
+            fn pack() {
+                use crate::{BoundedBits, Pack};
+
+                type Bounded = super::Bounded<3, 10>;
+                type Unit = super::Bounded<3, 3>;
+                assert_eq!(Unit::MAX_OFFSET, 0);
+                assert_eq!(<(Bounded, Unit, Bounded)>::MAX_PACKED, 63);
+
+                let value = (Bounded::new(5).unwrap(), Unit::MIN, Bounded::MAX);
+                let packed: u8 = value.pack();
+                assert_eq!(packed, 23);
+                assert_eq!(<(Bounded, Unit, Bounded)>::unpack(packed), Some(value));
+                assert_eq!(<(Bounded, Unit, Bounded)>::unpack(64_u8), None);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                assert_eq!(Full::MAX_OFFSET, Inner::MAX.abs_diff(Inner::MIN) as u128);
+                assert_eq!(<(Unit, Full)>::MAX_PACKED, Full::MAX_OFFSET);
+                let packed: u128 = (Unit::MIN, Full::MAX).pack();
+                assert_eq!(packed, Full::MAX_OFFSET);
+                assert_eq!(<(Full, Unit)>::unpack(packed), Some((Full::MAX, Unit::MIN)));
+            }
+
+            #[test]
+            fn key() {
+                use crate::BoundedKey;
+
+                type Bounded = super::Bounded<3, 10>;
+                assert_eq!(Bounded::COUNT, 8);
+                assert_eq!(Bounded::MIN.to_index(), 0);
+                assert_eq!(Bounded::MAX.to_index(), 7);
+                assert_eq!(Bounded::from_index(5).unwrap().get(), 8);
+                assert_eq!(Bounded::from_index(8), None);
+
+                type Low = super::Bounded<{ Inner::MIN }, { Inner::MIN + 3 }>;
+                assert_eq!(Low::COUNT, 4);
+                assert_eq!(Low::MAX.to_index(), 3);
+                assert_eq!(Low::from_index(1).unwrap().get(), Inner::MIN + 1);
+
+                type High = super::Bounded<{ Inner::MAX - 3 }, { Inner::MAX }>;
+                assert_eq!(High::COUNT, 4);
+                assert_eq!(High::MAX.to_index(), 3);
+                assert_eq!(High::from_index(0).unwrap(), High::MIN);
+
+                type Empty = super::Bounded<4, 3>;
+                assert_eq!(Empty::COUNT, 0);
+            }
+
             #[test]
             fn arithmetic() {
                 if false {
@@ -1198,6 +1834,329 @@ macro_rules! define_bounded_integers {
                 assert_eq!(Bounded::from_str_radix("3", 2).unwrap_err().kind(), InvalidDigit);
             }
 
+            #[test]
+            #[cfg(feature = "clap4")]
+            fn clap() {
+                use alloc::string::ToString;
+                use clap4::error::ErrorKind;
+                use clap4::{value_parser, Arg, Command};
+
+                let command = Command::new("test")
+                    .arg(Arg::new("n").long("n").value_parser(value_parser!(super::Bounded<2, 5>)));
+                let matches = command.clone().try_get_matches_from(["test", "--n", "4"]).unwrap();
+                assert_eq!(*matches.get_one::<super::Bounded<2, 5>>("n").unwrap(), 4);
+
+                let error = command.clone().try_get_matches_from(["test", "--n", "6"]).unwrap_err();
+                assert_eq!(error.kind(), ErrorKind::ValueValidation);
+                assert!(error.to_string().contains("expected an integer in 2..=5"));
+                let error = command.try_get_matches_from(["test", "--n", "x"]).unwrap_err();
+                assert_eq!(error.kind(), ErrorKind::ValueValidation);
+            }
+
+            #[test]
+            #[cfg(feature = "defmt1")]
+            fn defmt() {
+                use defmt1::export::{fetch_bytes, make_formatter};
+                use defmt1::Format;
+
+                let n = super::Bounded::<3, 11>::new(7).unwrap();
+                n.format(make_formatter());
+                let bounded = fetch_bytes();
+                n.get().format(make_formatter());
+                let primitive = fetch_bytes();
+
+                // The first two bytes are the interned format string, which differs every time
+                // when testing.
+                assert_eq!(bounded[2..], primitive[2..]);
+                assert!(bounded[2..].contains(&7));
+            }
+
+            #[test]
+            #[cfg(feature = "ufmt02")]
+            fn ufmt() {
+                let n = super::Bounded::<3, 11>::new(7).unwrap();
+                assert_eq!(crate::ufmt_tests::display(&n).as_str(), "7");
+                assert_eq!(crate::ufmt_tests::debug(&n).as_str(), "7");
+                $($(if $signed)? {
+                    let n = super::Bounded::<-11, 3>::new(-10).unwrap();
+                    assert_eq!(crate::ufmt_tests::display(&n).as_str(), "-10");
+                })?
+            }
+
+            #[test]
+            fn slices() {
+                type Bounded = super::Bounded<2, 5>;
+
+                let mut inner = [2, 3, 4, 5, 2];
+                let bounded = Bounded::new_slice(&inner).unwrap();
+                assert_eq!(bounded[1], Bounded::new(3).unwrap());
+                assert_eq!(Bounded::as_inner_slice(bounded), inner);
+                assert_eq!(Bounded::new_slice(&[2, 3, 6, 1]), Err(2));
+                assert_eq!(Bounded::new_slice(&[]), Ok(&[][..]));
+
+                let bounded = Bounded::new_slice_mut(&mut inner[1..]).unwrap();
+                bounded[0] = Bounded::MAX;
+                assert_eq!(inner, [2, 5, 4, 5, 2]);
+                assert_eq!(Bounded::new_slice_mut(&mut [1]), Err(0));
+
+                let array = Bounded::new_array(inner).unwrap();
+                assert_eq!(*Bounded::as_inner_array(&array), inner);
+                assert_eq!(Bounded::new_array([5, 6]), Err(1));
+                assert_eq!(Bounded::new_array_ref(&inner), Ok(&array));
+                Bounded::new_array_mut(&mut inner).unwrap()[4] = Bounded::MAX;
+                assert_eq!(inner, [2, 5, 4, 5, 5]);
+                assert_eq!(Bounded::new_array_mut(&mut [0; 3]), Err(0));
+            }
+
+            #[test]
+            #[cfg(feature = "borsh1")]
+            fn borsh() {
+                use alloc::{format, vec};
+                use borsh1::schema::{Definition, Fields};
+                use borsh1::BorshSchema;
+
+                type Bounded = super::Bounded<2, 5>;
+
+                let bytes = borsh1::to_vec(&Bounded::new(4).unwrap()).unwrap();
+                assert_eq!(bytes, borsh1::to_vec(&(4 as Inner)).unwrap());
+                assert_eq!(borsh1::from_slice::<Bounded>(&bytes).unwrap(), 4);
+
+                let bytes = borsh1::to_vec(&(6 as Inner)).unwrap();
+                let error = borsh1::from_slice::<Bounded>(&bytes).unwrap_err();
+                assert_eq!(error.kind(), borsh1::io::ErrorKind::InvalidData);
+
+                let name = stringify!($name);
+                assert_eq!(Bounded::declaration(), format!("{name}<2, 5>"));
+                let container = borsh1::schema_container_of::<Bounded>();
+                let fields = Fields::UnnamedFields(vec![Inner::declaration()]);
+                assert_eq!(
+                    container.get_definition(&Bounded::declaration()),
+                    Some(&Definition::Struct { fields }),
+                );
+                assert!(container.get_definition(&Inner::declaration()).is_some());
+            }
+
+            #[test]
+            #[cfg(feature = "bytemuck1")]
+            fn bytemuck() {
+                use bytemuck1::checked::{self, CheckedCastError};
+
+                type Bounded = super::Bounded<2, 5>;
+
+                let inner: [Inner; 3] = [2, 5, 3];
+                let bytes: &[u8] = bytemuck1::cast_slice(&inner);
+                let bounded = checked::try_cast_slice::<u8, Bounded>(bytes).unwrap();
+                assert_eq!(Bounded::as_inner_slice(bounded), inner);
+                assert_eq!(bytemuck1::cast_slice::<Bounded, u8>(bounded), bytes);
+
+                let inner: [Inner; 3] = [2, 6, 3];
+                assert_eq!(
+                    checked::try_cast_slice::<u8, Bounded>(bytemuck1::cast_slice(&inner)),
+                    Err(CheckedCastError::InvalidBitPattern),
+                );
+                assert_eq!(
+                    checked::try_from_bytes::<Bounded>(&[]),
+                    Err(CheckedCastError::PodCastError(bytemuck1::PodCastError::SizeMismatch)),
+                );
+            }
+
+            #[test]
+            fn bytes() {
+                use crate::ParseErrorKind::*;
+
+                type Bounded = super::Bounded<3, 100>;
+
+                let n: Inner = 66;
+                let bounded = Bounded::new(n).unwrap();
+                assert_eq!(bounded.to_le_bytes(), n.to_le_bytes());
+                assert_eq!(bounded.to_be_bytes(), n.to_be_bytes());
+                assert_eq!(bounded.to_ne_bytes(), n.to_ne_bytes());
+                assert_eq!(Bounded::try_from_le_bytes(n.to_le_bytes()).unwrap(), bounded);
+                assert_eq!(Bounded::try_from_be_bytes(n.to_be_bytes()).unwrap(), bounded);
+                assert_eq!(Bounded::try_from_ne_bytes(n.to_ne_bytes()).unwrap(), bounded);
+
+                let low: Inner = 2;
+                let high: Inner = 101;
+                let kind = |r: Result<Bounded, crate::ParseError>| r.unwrap_err().kind();
+                assert_eq!(kind(Bounded::try_from_le_bytes(low.to_le_bytes())), BelowMin);
+                assert_eq!(kind(Bounded::try_from_be_bytes(high.to_be_bytes())), AboveMax);
+
+                const MIN_BYTES: [u8; core::mem::size_of::<Inner>()] = Bounded::MIN.to_be_bytes();
+                const MIN: Bounded = match Bounded::try_from_be_bytes(MIN_BYTES) {
+                    Ok(n) => n,
+                    Err(_) => panic!(),
+                };
+                assert_eq!(MIN, Bounded::MIN);
+            }
+
+            #[test]
+            #[cfg(feature = "zerocopy08")]
+            fn zerocopy() {
+                use zerocopy08::IntoBytes;
+
+                type Bounded = super::Bounded<2, 5>;
+
+                let bounded = [Bounded::MIN, Bounded::new(3).unwrap(), Bounded::MAX];
+                let inner: [Inner; 3] = [2, 3, 5];
+                assert_eq!(bounded.as_bytes(), inner.as_bytes());
+            }
+
+            #[test]
+            #[cfg(feature = "__test_pyo3028")]
+            fn pyo3() {
+                use alloc::string::ToString;
+                use pyo3028::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+                use pyo3028::types::PyAnyMethods;
+                use pyo3028::{IntoPyObject, Python};
+
+                type Bounded = super::Bounded<2, 5>;
+
+                Python::attach(|py| {
+                    let obj = Bounded::new(4).unwrap().into_pyobject(py).unwrap();
+                    assert_eq!(obj.extract::<Inner>().unwrap(), 4);
+                    assert_eq!(obj.extract::<Bounded>().unwrap(), 4);
+                    assert_eq!((&Bounded::MAX).into_pyobject(py).unwrap().extract::<Inner>().unwrap(), 5);
+
+                    let obj = (6 as Inner).into_pyobject(py).unwrap();
+                    let error = obj.extract::<Bounded>().unwrap_err();
+                    assert!(error.is_instance_of::<PyValueError>(py));
+                    assert_eq!(error.value(py).to_string(), "6 is not in 2..=5");
+
+                    let obj = py.eval(c"-2 ** 200", None, None).unwrap();
+                    let error = obj.extract::<Bounded>().unwrap_err();
+                    assert!(error.is_instance_of::<PyOverflowError>(py));
+                    assert!(error.value(py).to_string().ends_with(" is not in 2..=5"));
+
+                    let obj = py.eval(c"'4'", None, None).unwrap();
+                    assert!(obj.extract::<Bounded>().unwrap_err().is_instance_of::<PyTypeError>(py));
+                });
+            }
+
+            #[test]
+            #[cfg(feature = "__test_rusqlite040")]
+            fn rusqlite() {
+                use rusqlite040::{Connection, Error};
+
+                type Bounded = super::Bounded<2, 5>;
+
+                let db = Connection::open_in_memory().unwrap();
+                db.execute("CREATE TABLE t (n INTEGER)", ()).unwrap();
+                db.execute("INSERT INTO t VALUES (?1), (6)", (Bounded::new(4).unwrap(),)).unwrap();
+                let mut statement = db.prepare("SELECT n FROM t").unwrap();
+                let mut rows = statement.query(()).unwrap();
+                let row = rows.next().unwrap().unwrap();
+                assert_eq!(row.get::<_, Bounded>(0).unwrap(), 4);
+                let row = rows.next().unwrap().unwrap();
+                assert!(matches!(row.get::<_, Bounded>(0), Err(Error::IntegralValueOutOfRange(0, 6))));
+            }
+
+            #[test]
+            #[cfg(feature = "schemars1")]
+            fn schemars() {
+                use serde_json1::json;
+
+                let schema = schemars1::schema_for!(super::Bounded<2, 5>);
+                let inner = schemars1::schema_for!(Inner);
+                assert_eq!(schema.get("type"), Some(&json!("integer")));
+                assert_eq!(schema.get("format"), inner.get("format"));
+                assert_eq!(schema.get("minimum"), Some(&json!(2)));
+                assert_eq!(schema.get("maximum"), Some(&json!(5)));
+            }
+
+            #[test]
+            #[cfg(feature = "utoipa5")]
+            fn utoipa() {
+                use utoipa5::{PartialSchema, ToSchema};
+
+                let schema = serde_json1::to_value(super::Bounded::<2, 5>::schema()).unwrap();
+                let inner = serde_json1::to_value(Inner::schema()).unwrap();
+                assert_eq!(schema["type"], "integer");
+                assert_eq!(schema["format"], inner["format"]);
+                assert_eq!(schema["minimum"], 2);
+                assert_eq!(schema["maximum"], 5);
+                assert_eq!(super::Bounded::<2, 5>::name(), concat!(stringify!($name), "_2_5"));
+            }
+
+            #[test]
+            fn to_ascii() {
+                type Minutes = super::Bounded<0, 59>;
+                assert_eq!(Minutes::MAX_LEN, 2);
+                let mut buf = [0; Minutes::MAX_LEN];
+                assert_eq!(Minutes::MIN.to_ascii(&mut buf), "0");
+                assert_eq!(Minutes::new(7).unwrap().to_ascii(&mut buf), "7");
+                assert_eq!(Minutes::MAX.to_ascii(&mut buf), "59");
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                let mut buf = [0; Full::MAX_LEN];
+                for n in [Inner::MIN, Inner::MIN / 3, 0, 1, 10, 99, 100, Inner::MAX / 7, Inner::MAX] {
+                    let s = Full::new(n).unwrap().to_ascii(&mut buf);
+                    assert_eq!(s.parse::<Inner>().unwrap(), n);
+                }
+
+                $($(if $signed)? {
+                    type Signed = super::Bounded<-100, 5>;
+                    let mut buf = [0; 10];
+                    assert_eq!(Signed::MIN.to_ascii(&mut buf), "-100");
+                    assert_eq!(Signed::new(-9).unwrap().to_ascii(&mut buf), "-9");
+                    assert_eq!(Signed::MAX.to_ascii(&mut buf), "5");
+
+                    type Positive = super::Bounded<-1, 100>;
+                    assert_eq!(Positive::MAX_LEN, 3);
+                    let mut buf = [0; Positive::MAX_LEN];
+                    assert_eq!(Positive::MIN.to_ascii(&mut buf), "-1");
+                    assert_eq!(Positive::MAX.to_ascii(&mut buf), "100");
+                })?
+            }
+
+            #[test]
+            #[cfg(feature = "std")]
+            fn format() {
+                type Minutes = super::Bounded<0, 59>;
+                assert_eq!(Minutes::MAX_DIGITS, 2);
+                assert_eq!(Minutes::max_digits(2), 6);
+                assert_eq!(format!("{}", Minutes::new(7).unwrap().display_padded()), "07");
+                assert_eq!(format!("{}", Minutes::MAX.display_padded()), "59");
+                assert_eq!(format!("{:>4}", Minutes::MIN.display_padded()), "  00");
+                assert_eq!(format!("{}", Minutes::MAX.format_radix(2)), "111011");
+                assert_eq!(format!("{}", Minutes::MAX.format_radix(36)), "1n");
+                assert_eq!(Minutes::from_str_radix("1n", 36).unwrap(), Minutes::MAX);
+
+                type Full = super::Bounded<{ Inner::MIN }, { Inner::MAX }>;
+                for radix in 2..=36 {
+                    let mut digits = 0;
+                    for n in [Full::MIN, Full::MAX] {
+                        let s = format!("{}", n.format_radix(radix));
+                        assert_eq!(Full::from_str_radix(&s, radix).unwrap(), n);
+                        digits = digits.max(s.trim_start_matches('-').len());
+                    }
+                    assert_eq!(Full::max_digits(radix), digits);
+                }
+                for n in [Full::MIN, Full::MAX] {
+                    let s = format!("{}", n.display_padded());
+                    assert_eq!(s.parse::<Full>().unwrap(), n);
+                    assert_eq!(s.trim_start_matches('-').len(), Full::MAX_DIGITS);
+                }
+
+                $($(if $signed)? {
+                    type Signed = super::Bounded<-100, 5>;
+                    assert_eq!(Signed::MAX_DIGITS, 3);
+                    assert_eq!(Signed::MAX_LEN, 4);
+                    assert_eq!(format!("{}", Signed::MAX.display_padded()), "005");
+                    assert_eq!(format!("{:+}", Signed::MAX.display_padded()), "+005");
+                    assert_eq!(format!("{}", Signed::new(-7).unwrap().display_padded()), "-007");
+                    assert_eq!(format!("{}", Signed::MIN.format_radix(16)), "-64");
+                })?
+            }
+
+            #[test]
+            #[cfg(feature = "zerocopy06")]
+            fn zerocopy06() {
+                use zerocopy06::AsBytes;
+
+                let bounded = super::Bounded::<3, 10>::new(7).unwrap();
+                assert_eq!(bounded.as_bytes(), Inner::as_bytes(&7));
+            }
+
             #[test]
             #[cfg(feature = "num-traits02")]
             fn num() {
@@ -1359,4 +2318,33 @@ define_bounded_integers! {
     BoundedIsize isize signed -> isize,
 }
 
+#[cfg(feature = "rkyv08")]
+mod archived;
+#[cfg(feature = "rkyv08")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rkyv08")))]
+pub use archived::{
+    ArchivedBoundedI128, ArchivedBoundedI16, ArchivedBoundedI32, ArchivedBoundedI64,
+    ArchivedBoundedI8, ArchivedBoundedIsize, ArchivedBoundedU128, ArchivedBoundedU16,
+    ArchivedBoundedU32, ArchivedBoundedU64, ArchivedBoundedU8, ArchivedBoundedUsize,
+};
+
+mod atomic;
+#[cfg(target_has_atomic = "16")]
+pub use atomic::AtomicBoundedU16;
+#[cfg(target_has_atomic = "32")]
+pub use atomic::AtomicBoundedU32;
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicBoundedU64;
+#[cfg(target_has_atomic = "8")]
+pub use atomic::AtomicBoundedU8;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicBoundedUsize;
+
+mod endian;
+pub use endian::{BoundedI128Be, BoundedI16Be, BoundedI32Be, BoundedI64Be};
+pub use endian::{BoundedI128Le, BoundedI16Le, BoundedI32Le, BoundedI64Le};
+pub use endian::{BoundedU128Be, BoundedU16Be, BoundedU32Be, BoundedU64Be};
+pub use endian::{BoundedU128Le, BoundedU16Le, BoundedU32Le, BoundedU64Le};
+
 mod indexing;
+pub use indexing::GetBounded;
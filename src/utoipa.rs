@@ -0,0 +1,83 @@
+//! Helpers shared by the implementations of utoipa's traits.
+
+#[cfg(feature = "types")]
+use alloc::borrow::Cow;
+#[cfg(feature = "types")]
+use alloc::format;
+#[cfg(feature = "macro")]
+use alloc::vec::Vec;
+#[cfg(feature = "types")]
+use core::fmt::Display;
+use utoipa5::openapi::schema::{Object, Schema};
+use utoipa5::openapi::RefOr;
+use utoipa5::{Number, PartialSchema};
+
+/// The name of a bounded integer's schema, which includes its range, e.g. `BoundedU8_1_7`.
+#[cfg(feature = "types")]
+pub fn name(name: &str, min: impl Display, max: impl Display) -> Cow<'static, str> {
+    Cow::Owned(format!("{name}_{min}_{max}"))
+}
+
+/// The schema of a bounded integer, which is that of its inner integer with the bounds narrowed.
+///
+/// Bounds that don't fit in an OpenAPI integer are left as they are for the inner integer.
+pub fn schema<Inner>(min: Inner, max: Inner) -> RefOr<Schema>
+where
+    Inner: PartialSchema + Copy,
+    isize: TryFrom<Inner>,
+    usize: TryFrom<Inner>,
+{
+    let mut schema = Inner::schema();
+    if let Some(object) = object(&mut schema) {
+        if let Some(min) = number(min) {
+            object.minimum = Some(min);
+        }
+        if let Some(max) = number(max) {
+            object.maximum = Some(max);
+        }
+    }
+    schema
+}
+
+/// Lists the values of a bounded enum in its schema, if they all fit in OpenAPI integers.
+#[cfg(feature = "macro")]
+pub fn list_values<Inner>(schema: &mut RefOr<Schema>, values: &[Inner])
+where
+    Inner: Copy,
+    isize: TryFrom<Inner>,
+    usize: TryFrom<Inner>,
+{
+    let Some(object) = object(schema) else {
+        return;
+    };
+    let unsigned = values
+        .iter()
+        .map(|&v| usize::try_from(v).ok().map(Into::into));
+    let signed = values
+        .iter()
+        .map(|&v| isize::try_from(v).ok().map(Into::into));
+    if let Some(values) = unsigned.collect::<Option<Vec<_>>>() {
+        object.enum_values = Some(values);
+    } else if let Some(values) = signed.collect::<Option<Vec<_>>>() {
+        object.enum_values = Some(values);
+    }
+}
+
+fn object(schema: &mut RefOr<Schema>) -> Option<&mut Object> {
+    match schema {
+        RefOr::T(Schema::Object(object)) => Some(object),
+        _ => None,
+    }
+}
+
+fn number<Inner>(n: Inner) -> Option<Number>
+where
+    Inner: Copy,
+    isize: TryFrom<Inner>,
+    usize: TryFrom<Inner>,
+{
+    usize::try_from(n)
+        .map(Number::UInt)
+        .or_else(|_| isize::try_from(n).map(Number::Int))
+        .ok()
+}
@@ -36,3 +36,7 @@ mod primitives {
     #[repr(u8)]
     pub enum EnumUnsigned { 253..255 }
 }
+::bounded_integer::bounded_integer! {
+    #[repr(usize)]
+    pub struct StructUsize { 0..10 }
+}